@@ -0,0 +1,337 @@
+use procfs::ProcessCgroup;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// The path in the unified hierarchy, or the systemd hierarchy on cgroup v1 only hosts
+pub fn unified_path(cgroups: &[ProcessCgroup]) -> Option<String> {
+    cgroups
+        .iter()
+        .find(|x| x.hierarchy == 0)
+        .or_else(|| {
+            cgroups
+                .iter()
+                .find(|x| x.controllers.iter().any(|x| x == "name=systemd"))
+        })
+        .map(|x| x.pathname.clone())
+}
+
+// cgroup v2 is mounted at /sys/fs/cgroup, or /sys/fs/cgroup/unified on hybrid hosts
+pub fn unified_root() -> Option<PathBuf> {
+    ["/sys/fs/cgroup", "/sys/fs/cgroup/unified"]
+        .iter()
+        .map(PathBuf::from)
+        .find(|x| x.join("cgroup.controllers").exists())
+}
+
+pub fn read_file(root: &Path, path: &str, file: &str) -> Option<String> {
+    fs::read_to_string(root.join(path.trim_start_matches('/')).join(file)).ok()
+}
+
+// "max" means unlimited
+pub fn parse_value(s: &str) -> Option<u64> {
+    let s = s.trim();
+    if s == "max" {
+        Some(u64::MAX)
+    } else {
+        s.parse().ok()
+    }
+}
+
+// Flat keyed file like cpu.stat
+pub fn parse_keyed_value(s: &str, key: &str) -> Option<u64> {
+    s.lines().find_map(|x| {
+        let mut x = x.split_whitespace();
+        if x.next() == Some(key) {
+            x.next().and_then(|x| x.parse().ok())
+        } else {
+            None
+        }
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ContainerInfo {
+    pub runtime: &'static str,
+    pub id: String,
+}
+
+impl ContainerInfo {
+    pub fn short_id(&self) -> &str {
+        if is_container_id(&self.id) {
+            &self.id[0..12]
+        } else {
+            &self.id
+        }
+    }
+}
+
+fn is_container_id(x: &str) -> bool {
+    x.len() == 64 && x.chars().all(|x| x.is_ascii_hexdigit())
+}
+
+// The innermost container is found from the cgroup path.
+// Each runtime uses the different cgroup layout by the cgroup driver (systemd or cgroupfs).
+pub fn parse_container(path: &str) -> Option<ContainerInfo> {
+    let components: Vec<&str> = path.split('/').filter(|x| !x.is_empty()).collect();
+    for (i, c) in components.iter().enumerate().rev() {
+        let parent = if i > 0 { components[i - 1] } else { "" };
+        let scope = c.trim_end_matches(".scope");
+        let info = |runtime, id: &str| {
+            Some(ContainerInfo {
+                runtime,
+                id: String::from(id),
+            })
+        };
+
+        // systemd driver: docker-<id>.scope, kubepods-<pod>.slice:cri-containerd:<id>
+        let prefixes = [
+            ("docker-", "docker"),
+            ("cri-containerd-", "containerd"),
+            ("crio-conmon-", "crio"),
+            ("crio-", "crio"),
+            ("libpod-conmon-", "podman"),
+            ("libpod-", "podman"),
+        ];
+        for (prefix, runtime) in &prefixes {
+            if let Some(id) = scope.strip_prefix(prefix) {
+                if is_container_id(id) {
+                    return info(runtime, id);
+                }
+            }
+        }
+        let fields: Vec<&str> = c.split(':').collect();
+        if fields.len() == 3 && is_container_id(fields[2]) {
+            match fields[1] {
+                "cri-containerd" => return info("containerd", fields[2]),
+                "crio" => return info("crio", fields[2]),
+                _ => (),
+            }
+        }
+
+        if let Some(name) = c
+            .strip_prefix("systemd-nspawn@")
+            .and_then(|x| x.strip_suffix(".service"))
+        {
+            return info("nspawn", name);
+        }
+        if let Some(name) = c.strip_prefix("lxc.payload.") {
+            return info("lxc", name);
+        }
+        if parent == "lxc" || parent == "lxc.payload" {
+            return info("lxc", c);
+        }
+
+        // cgroupfs driver: /docker/<id>, /libpod_parent/libpod-<id>, /kubepods/<qos>/pod<uid>/<id>
+        if is_container_id(c) {
+            let runtime = match parent {
+                "docker" => "docker",
+                "crio" => "crio",
+                _ if components.contains(&"kubepods") => "cri",
+                _ => "containerd",
+            };
+            return info(runtime, c);
+        }
+    }
+    None
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PodInfo {
+    pub uid: String,
+    pub qos: &'static str,
+}
+
+// Pod cgroups are kubepods-<qos>-pod<uid>.slice by systemd driver, and /kubepods/<qos>/pod<uid> by cgroupfs driver.
+// Guaranteed pods have no QoS level in the path.
+pub fn parse_pod(path: &str) -> Option<PodInfo> {
+    for c in path.split(&['/', ':'][..]) {
+        let c = c.trim_end_matches(".slice");
+        let uid = if let Some(x) = c.strip_prefix("kubepods-") {
+            let x = x
+                .trim_start_matches("burstable-")
+                .trim_start_matches("besteffort-");
+            x.strip_prefix("pod").map(|x| x.replace('_', "-"))
+        } else if path.contains("kubepods") {
+            c.strip_prefix("pod").map(String::from)
+        } else {
+            None
+        };
+        if let Some(uid) = uid {
+            let qos = if path.contains("burstable") {
+                "Burstable"
+            } else if path.contains("besteffort") {
+                "BestEffort"
+            } else {
+                "Guaranteed"
+            };
+            return Some(PodInfo { uid, qos });
+        }
+    }
+    None
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UnitInfo {
+    pub unit: String,
+    pub slice: String,
+}
+
+const UNIT_SUFFIXES: [&str; 5] = [".service", ".scope", ".socket", ".mount", ".swap"];
+
+// The innermost unit and slice are found from the cgroup path.
+// Units of the user manager are prefixed by it like user@1000.service/app.service.
+pub fn parse_unit(path: &str) -> Option<UnitInfo> {
+    let mut unit: Option<String> = None;
+    let mut manager: Option<&str> = None;
+    let mut slice = String::from("-.slice");
+    for c in path.split('/').filter(|x| !x.is_empty()) {
+        if c.ends_with(".slice") {
+            slice = String::from(c);
+        } else if UNIT_SUFFIXES.iter().any(|x| c.ends_with(x)) {
+            if c.starts_with("user@") {
+                manager = Some(c);
+            }
+            unit = Some(String::from(c));
+        } else {
+            // The sub-cgroups under the unit like /docker-<id>.scope/init
+            break;
+        }
+    }
+    let unit = unit?;
+    let unit = match manager {
+        Some(manager) if manager != unit => format!("{}/{}", manager, unit),
+        _ => unit,
+    };
+    Some(UnitInfo { unit, slice })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_value("1024\n"), Some(1024));
+        assert_eq!(parse_value("max\n"), Some(u64::MAX));
+        assert_eq!(parse_value(""), None);
+
+        let stat = "usage_usec 100\nnr_periods 10\nnr_throttled 3\nthrottled_usec 5000\n";
+        assert_eq!(parse_keyed_value(stat, "nr_throttled"), Some(3));
+        assert_eq!(parse_keyed_value(stat, "nr_bursts"), None);
+    }
+
+    #[test]
+    fn test_parse_container() {
+        let id = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let check = |path: &str, runtime: &str, id: &str| {
+            let info = parse_container(path).unwrap();
+            assert_eq!((info.runtime, info.id.as_str()), (runtime, id));
+        };
+
+        check(&format!("/docker/{}", id), "docker", id);
+        check(&format!("/system.slice/docker-{}.scope", id), "docker", id);
+        check(
+            &format!("/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1234.slice/cri-containerd-{}.scope", id),
+            "containerd",
+            id,
+        );
+        check(
+            &format!("/system.slice/containerd.service/kubepods-burstable-pod1234.slice:cri-containerd:{}", id),
+            "containerd",
+            id,
+        );
+        check(&format!("/kubepods/besteffort/pod1234/{}", id), "cri", id);
+        check(&format!("/default/{}", id), "containerd", id);
+        check(
+            &format!("/machine.slice/libpod-{}.scope/container", id),
+            "podman",
+            id,
+        );
+        check(&format!("/libpod_parent/libpod-{}", id), "podman", id);
+        check(&format!("/kubepods.slice/crio-{}.scope", id), "crio", id);
+        check("/lxc.payload.web/init.scope", "lxc", "web");
+        check("/lxc/web", "lxc", "web");
+        check(
+            "/machine.slice/systemd-nspawn@web.service/payload",
+            "nspawn",
+            "web",
+        );
+        assert_eq!(
+            parse_container("/user.slice/user-1000.slice/session-1.scope"),
+            None
+        );
+        assert_eq!(parse_container("/"), None);
+
+        let info = parse_container(&format!("/docker/{}", id)).unwrap();
+        assert_eq!(info.short_id(), "0123456789ab");
+    }
+
+    #[test]
+    fn test_parse_pod() {
+        let check = |path: &str, uid: &str, qos: &str| {
+            let info = parse_pod(path).unwrap();
+            assert_eq!((info.uid.as_str(), info.qos), (uid, qos));
+        };
+
+        check(
+            "/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod12ab_34cd.slice/cri-containerd-0123.scope",
+            "12ab-34cd",
+            "Burstable",
+        );
+        check(
+            "/kubepods.slice/kubepods-pod12ab_34cd.slice/crio-0123.scope",
+            "12ab-34cd",
+            "Guaranteed",
+        );
+        check(
+            "/system.slice/containerd.service/kubepods-besteffort-pod12ab_34cd.slice:cri-containerd:0123",
+            "12ab-34cd",
+            "BestEffort",
+        );
+        check(
+            "/kubepods/besteffort/pod12ab-34cd/0123",
+            "12ab-34cd",
+            "BestEffort",
+        );
+        check("/kubepods/pod12ab-34cd/0123", "12ab-34cd", "Guaranteed");
+        assert_eq!(parse_pod("/kubepods.slice/kubepods-burstable.slice"), None);
+        assert_eq!(parse_pod("/system.slice/pod.service"), None);
+    }
+
+    #[test]
+    fn test_parse_unit() {
+        let check = |path: &str, unit: &str, slice: &str| {
+            let info = parse_unit(path).unwrap();
+            assert_eq!((info.unit.as_str(), info.slice.as_str()), (unit, slice));
+        };
+
+        check(
+            "/system.slice/nginx.service",
+            "nginx.service",
+            "system.slice",
+        );
+        check("/init.scope", "init.scope", "-.slice");
+        check(
+            "/user.slice/user-1000.slice/session-3.scope",
+            "session-3.scope",
+            "user-1000.slice",
+        );
+        check(
+            "/user.slice/user-1000.slice/user@1000.service/app.slice/app-foo.service",
+            "user@1000.service/app-foo.service",
+            "app.slice",
+        );
+        check(
+            "/user.slice/user-1000.slice/user@1000.service/init.scope",
+            "user@1000.service/init.scope",
+            "user-1000.slice",
+        );
+        check(
+            "/system.slice/docker-0123.scope/init",
+            "docker-0123.scope",
+            "system.slice",
+        );
+        assert_eq!(parse_unit("/"), None);
+        assert_eq!(parse_unit("/docker/0123"), None);
+    }
+}
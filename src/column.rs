@@ -1,4 +1,6 @@
+use crate::columns::ConfigColumnKind;
 use crate::config::{Config, ConfigColumnAlign, ConfigSortOrder};
+use crate::history::History;
 use crate::process::ProcessInfo;
 
 pub trait Column {
@@ -12,6 +14,39 @@ pub trait Column {
         true
     }
 
+    fn require_socket(&self) -> bool {
+        false
+    }
+
+    fn require_fd(&self) -> bool {
+        false
+    }
+
+    fn require_schedstat(&self) -> bool {
+        false
+    }
+
+    fn require_blkio_delay(&self) -> bool {
+        false
+    }
+
+    fn require_prev_status(&self) -> bool {
+        false
+    }
+
+    fn require_history(&self) -> bool {
+        false
+    }
+
+    fn history_value(&self, _pid: i32) -> Option<f64> {
+        None
+    }
+
+    fn apply_history(&mut self, _kind: &ConfigColumnKind, _history: &History) {}
+
+    // Columns which cache information across processes re-read it when they are re-used
+    fn refresh(&mut self) {}
+
     fn display_header(
         &self,
         align: &ConfigColumnAlign,
@@ -186,6 +221,15 @@ macro_rules! column_default_update_width {
     };
 }
 
+#[macro_export]
+macro_rules! column_default_history_value {
+    () => {
+        fn history_value(&self, pid: i32) -> Option<f64> {
+            self.raw_contents.get(&pid).map(|x| *x as f64)
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! column_default_get_width {
     () => {
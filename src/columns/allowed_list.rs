@@ -0,0 +1,90 @@
+use crate::process::ProcessInfo;
+use crate::Column;
+use procfs::process::Status;
+use std::cmp;
+use std::collections::HashMap;
+
+type ListFn = fn(&Status) -> Option<&Vec<(u32, u32)>>;
+
+pub struct AllowedList {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    list: ListFn,
+    ranges: HashMap<i32, Vec<(u32, u32)>>,
+}
+
+impl AllowedList {
+    pub fn new(header: Option<String>, default_header: &str, list: ListFn) -> Self {
+        let header = header.unwrap_or_else(|| String::from(default_header));
+        let unit = String::from("");
+        AllowedList {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            list,
+            ranges: HashMap::new(),
+        }
+    }
+}
+
+// The format is the same as Cpus_allowed_list like "0-3,8"
+fn format_ranges(ranges: &[(u32, u32)]) -> String {
+    let ranges: Vec<String> = ranges
+        .iter()
+        .map(|(start, end)| {
+            if start == end {
+                format!("{}", start)
+            } else {
+                format!("{}-{}", start, end)
+            }
+        })
+        .collect();
+    ranges.join(",")
+}
+
+#[cfg(target_os = "linux")]
+impl Column for AllowedList {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let ranges = proc
+            .curr_status
+            .as_ref()
+            .and_then(|x| (self.list)(x))
+            .cloned()
+            .unwrap_or_default();
+        let fmt_content = format_ranges(&ranges);
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+        self.ranges.insert(proc.pid, ranges);
+    }
+
+    // Numeric keyword matches if the CPU or node is included in the list
+    fn find_exact(&self, pid: i32, keyword: &str) -> bool {
+        if let Ok(x) = keyword.parse::<u32>() {
+            if let Some(ranges) = self.ranges.get(&pid) {
+                return ranges.iter().any(|(start, end)| *start <= x && x <= *end);
+            }
+        }
+        if let Some(content) = self.fmt_contents.get(&pid) {
+            content == keyword
+        } else {
+            false
+        }
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_find_partial!();
+    crate::column_default_sorted_pid!(String);
+    crate::column_default_apply_visible!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
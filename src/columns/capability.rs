@@ -0,0 +1,143 @@
+use crate::process::ProcessInfo;
+use crate::Column;
+use procfs::process::Status;
+use std::cmp;
+use std::collections::HashMap;
+
+// The index is the capability number defined in linux/capability.h
+const CAP_NAMES: [&str; 41] = [
+    "chown",
+    "dac_override",
+    "dac_read_search",
+    "fowner",
+    "fsetid",
+    "kill",
+    "setgid",
+    "setuid",
+    "setpcap",
+    "linux_immutable",
+    "net_bind_service",
+    "net_broadcast",
+    "net_admin",
+    "net_raw",
+    "ipc_lock",
+    "ipc_owner",
+    "sys_module",
+    "sys_rawio",
+    "sys_chroot",
+    "sys_ptrace",
+    "sys_pacct",
+    "sys_admin",
+    "sys_boot",
+    "sys_nice",
+    "sys_resource",
+    "sys_time",
+    "sys_tty_config",
+    "mknod",
+    "lease",
+    "audit_write",
+    "audit_control",
+    "setfcap",
+    "mac_override",
+    "mac_admin",
+    "syslog",
+    "wake_alarm",
+    "block_suspend",
+    "audit_read",
+    "perfmon",
+    "bpf",
+    "checkpoint_restore",
+];
+
+pub struct Capability {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    mask: fn(&Status) -> Option<u64>,
+}
+
+impl Capability {
+    pub fn new(
+        header: Option<String>,
+        default_header: &str,
+        mask: fn(&Status) -> Option<u64>,
+    ) -> Self {
+        let header = header.unwrap_or_else(|| String::from(default_header));
+        let unit = String::from("");
+        Capability {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            mask,
+        }
+    }
+}
+
+// The full set like CapBnd of root is too long, so it is shown as "all"
+fn decode(mask: u64) -> String {
+    let all = (1u64 << CAP_NAMES.len()) - 1;
+    if mask & all == all {
+        return String::from("all");
+    }
+    let mut names = Vec::new();
+    for i in 0..64 {
+        if mask & (1 << i) != 0 {
+            if let Some(x) = CAP_NAMES.get(i) {
+                names.push(String::from(*x));
+            } else {
+                names.push(format!("cap_{}", i));
+            }
+        }
+    }
+    names.join(",")
+}
+
+#[cfg(target_os = "linux")]
+impl Column for Capability {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let mask = proc.curr_status.as_ref().and_then(|x| (self.mask)(x));
+        let (fmt_content, raw_content) = if let Some(mask) = mask {
+            (decode(mask), mask)
+        } else {
+            (String::from(""), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn find_exact(&self, pid: i32, keyword: &str) -> bool {
+        if let Some(content) = self.fmt_contents.get(&pid) {
+            content.split(',').any(|x| x == keyword)
+        } else {
+            false
+        }
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_find_partial!();
+    crate::column_default_sorted_pid!(u64);
+    crate::column_default_apply_visible!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode(0), "");
+        assert_eq!(decode(0x0000_0000_0020_0400), "net_bind_service,sys_admin");
+        assert_eq!(decode(0x0000_01ff_ffff_ffff), "all");
+        assert_eq!(decode(0x0000_0000_0000_0001 | 1 << 50), "chown,cap_50");
+    }
+}
@@ -0,0 +1,44 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Cgroup {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+}
+
+impl Cgroup {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Cgroup"));
+        let unit = String::from("");
+        Cgroup {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for Cgroup {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = proc
+            .curr_proc
+            .cgroups()
+            .ok()
+            .and_then(|x| crate::cgroup::unified_path(&x))
+            .unwrap_or_default();
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
@@ -0,0 +1,94 @@
+use crate::process::ProcessInfo;
+use crate::util::bytify;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+// A value read from the cgroup v2 interface file of the cgroup which the process belongs to
+pub struct CgroupValue {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    root: Option<PathBuf>,
+    file: &'static str,
+    key: Option<&'static str>,
+    bytes: bool,
+    cache: HashMap<String, Option<u64>>,
+}
+
+impl CgroupValue {
+    pub fn new(
+        header: Option<String>,
+        default_header: &str,
+        unit: &str,
+        file: &'static str,
+        key: Option<&'static str>,
+        bytes: bool,
+    ) -> Self {
+        let header = header.unwrap_or_else(|| String::from(default_header));
+        let unit = String::from(unit);
+        CgroupValue {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            root: crate::cgroup::unified_root(),
+            file,
+            key,
+            bytes,
+            cache: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for CgroupValue {
+    fn refresh(&mut self) {
+        self.cache.clear();
+    }
+
+    fn add(&mut self, proc: &ProcessInfo) {
+        let path = proc
+            .curr_proc
+            .cgroups()
+            .ok()
+            .and_then(|x| x.into_iter().find(|x| x.hierarchy == 0))
+            .map(|x| x.pathname);
+
+        // Processes in the same cgroup share the value
+        let value = match (&self.root, path) {
+            (Some(root), Some(path)) => {
+                let (file, key) = (self.file, self.key);
+                *self.cache.entry(path).or_insert_with_key(|path| {
+                    let s = crate::cgroup::read_file(root, path, file)?;
+                    if let Some(key) = key {
+                        crate::cgroup::parse_keyed_value(&s, key)
+                    } else {
+                        crate::cgroup::parse_value(&s)
+                    }
+                })
+            }
+            _ => None,
+        };
+
+        let (fmt_content, raw_content) = match value {
+            Some(u64::MAX) => (String::from("max"), u64::MAX),
+            Some(x) if self.bytes => (bytify(x), x),
+            Some(x) => (format!("{}", x), x),
+            None => (String::from(""), 0),
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn available(&self) -> bool {
+        self.root.is_some()
+    }
+
+    column_default!(u64);
+}
@@ -0,0 +1,129 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Container {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    resolve_name: bool,
+    names: HashMap<String, Option<String>>,
+}
+
+impl Container {
+    pub fn new(header: Option<String>, resolve_name: bool) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Container"));
+        let unit = String::from("");
+        Container {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            resolve_name,
+            names: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for Container {
+    fn refresh(&mut self) {
+        self.names.clear();
+    }
+
+    fn add(&mut self, proc: &ProcessInfo) {
+        let info = proc
+            .curr_proc
+            .cgroups()
+            .ok()
+            .and_then(|x| crate::cgroup::unified_path(&x))
+            .and_then(|x| crate::cgroup::parse_container(&x));
+
+        let fmt_content = if let Some(info) = info {
+            let name = if self.resolve_name {
+                self.names
+                    .entry(info.id.clone())
+                    .or_insert_with(|| resolve_name(info.runtime, &info.id))
+                    .clone()
+            } else {
+                None
+            };
+            let name = name.unwrap_or_else(|| String::from(info.short_id()));
+            format!("{}:{}", info.runtime, name)
+        } else {
+            String::from("")
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+// Container names are read from the local state of each runtime without daemon.
+// containerd keeps names in its database, so they are not resolved.
+#[cfg(target_os = "linux")]
+fn resolve_name(runtime: &str, id: &str) -> Option<String> {
+    match runtime {
+        "docker" => {
+            let path = format!("/var/lib/docker/containers/{}/config.v2.json", id);
+            let s = std::fs::read_to_string(path).ok()?;
+            docker_name(&s)
+        }
+        "podman" | "crio" => {
+            let path = "/var/lib/containers/storage/overlay-containers/containers.json";
+            let s = std::fs::read_to_string(path).ok()?;
+            storage_name(&s, id)
+        }
+        _ => None,
+    }
+}
+
+// config.v2.json is like {"ID":"...","Name":"/web",...}
+#[cfg(target_os = "linux")]
+fn docker_name(s: &str) -> Option<String> {
+    let config: serde_json::Value = serde_json::from_str(s).ok()?;
+    let name = config.get("Name")?.as_str()?;
+    Some(String::from(name.trim_start_matches('/')))
+}
+
+// containers.json is an array of containers like {"id":"...","names":["web"],...}
+#[cfg(target_os = "linux")]
+fn storage_name(s: &str, id: &str) -> Option<String> {
+    let containers: serde_json::Value = serde_json::from_str(s).ok()?;
+    let container = containers
+        .as_array()?
+        .iter()
+        .find(|x| x.get("id").and_then(|x| x.as_str()) == Some(id))?;
+    let name = container.get("names")?.as_array()?.first()?.as_str()?;
+    Some(String::from(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_docker_name() {
+        let s = r#"{"ID":"0123abcd","Config":{"Hostname":"0123abcd"},"Name":"/web"}"#;
+        assert_eq!(docker_name(s), Some(String::from("web")));
+        assert_eq!(docker_name("{}"), None);
+    }
+
+    #[test]
+    fn test_storage_name() {
+        let s = r#"[
+            {"id":"aaaa","names":[],"image":"1111"},
+            {"id":"bbbb","names":["db"],"image":"2222"}
+        ]"#;
+        assert_eq!(storage_name(s, "aaaa"), None);
+        assert_eq!(storage_name(s, "bbbb"), Some(String::from("db")));
+        assert_eq!(storage_name(s, "cccc"), None);
+    }
+}
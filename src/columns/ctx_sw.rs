@@ -0,0 +1,74 @@
+use crate::process::ProcessInfo;
+use crate::util::bytify;
+use crate::{column_default, Column};
+use procfs::process::Status;
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct CtxSw {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    count: fn(&Status) -> Option<u64>,
+    rate: bool,
+}
+
+impl CtxSw {
+    pub fn new(
+        header: Option<String>,
+        default_header: &str,
+        count: fn(&Status) -> Option<u64>,
+        rate: bool,
+    ) -> Self {
+        let header = header.unwrap_or_else(|| String::from(default_header));
+        let unit = if rate {
+            String::from("[/s]")
+        } else {
+            String::from("")
+        };
+        CtxSw {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            count,
+            rate,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for CtxSw {
+    fn require_prev_status(&self) -> bool {
+        self.rate
+    }
+
+    fn add(&mut self, proc: &ProcessInfo) {
+        let curr = proc.curr_status.as_ref().and_then(|x| (self.count)(x));
+        let prev = proc.prev_status.as_ref().and_then(|x| (self.count)(x));
+        let (fmt_content, raw_content) = match (curr, prev) {
+            (Some(curr), Some(prev)) if self.rate => {
+                let rate = curr.saturating_sub(prev) as f64 / proc.interval.as_secs_f64();
+                (format!("{:.1}", rate), (rate * 1000.0) as u64)
+            }
+            (Some(curr), _) if !self.rate => (bytify(curr), curr),
+            _ => (String::from(""), 0),
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn history_value(&self, pid: i32) -> Option<f64> {
+        if self.rate {
+            self.raw_contents.get(&pid).map(|x| *x as f64 / 1000.0)
+        } else {
+            self.raw_contents.get(&pid).map(|x| *x as f64)
+        }
+    }
+
+    column_default!(u64);
+}
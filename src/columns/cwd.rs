@@ -0,0 +1,110 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Cwd {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+}
+
+impl Cwd {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Cwd"));
+        let unit = String::from("");
+        Cwd {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for Cwd {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = if let Ok(x) = proc.curr_proc.cwd() {
+            x.to_string_lossy().to_string()
+        } else {
+            String::from("")
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(target_os = "macos")]
+impl Column for Cwd {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = get_cwd(proc.pid).unwrap_or_default();
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+// libproc's pidcwd is not implemented for macOS, so the vnode path info is read directly.
+// The structs follow sys/proc_info.h, and vnode_info before the path is 152 bytes.
+#[cfg(target_os = "macos")]
+const PROC_PIDVNODEPATHINFO: libc::c_int = 9;
+
+#[cfg(target_os = "macos")]
+#[repr(C)]
+#[allow(dead_code)]
+struct VnodeInfoPath {
+    vip_vi: [u64; 19],
+    vip_path: [libc::c_char; 1024],
+}
+
+#[cfg(target_os = "macos")]
+#[repr(C)]
+#[allow(dead_code)]
+struct ProcVnodePathInfo {
+    pvi_cdir: VnodeInfoPath,
+    pvi_rdir: VnodeInfoPath,
+}
+
+#[cfg(target_os = "macos")]
+extern "C" {
+    fn proc_pidinfo(
+        pid: libc::c_int,
+        flavor: libc::c_int,
+        arg: u64,
+        buffer: *mut libc::c_void,
+        buffersize: libc::c_int,
+    ) -> libc::c_int;
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(target_os = "macos")]
+fn get_cwd(pid: i32) -> Option<String> {
+    let mut info: ProcVnodePathInfo = unsafe { ::std::mem::zeroed() };
+    let size = ::std::mem::size_of::<ProcVnodePathInfo>() as libc::c_int;
+    let ret = unsafe {
+        proc_pidinfo(
+            pid,
+            PROC_PIDVNODEPATHINFO,
+            0,
+            (&mut info) as *mut ProcVnodePathInfo as *mut libc::c_void,
+            size,
+        )
+    };
+    if ret != size {
+        return None;
+    }
+    let path = unsafe { ::std::ffi::CStr::from_ptr(info.pvi_cdir.vip_path.as_ptr()) };
+    Some(path.to_string_lossy().to_string())
+}
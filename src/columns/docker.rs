@@ -91,20 +91,21 @@ impl Docker {
 #[cfg(target_os = "linux")]
 impl Column for Docker {
     fn add(&mut self, proc: &ProcessInfo) {
-        let fmt_content = if let Ok(cgroups) = proc.curr_proc.cgroups() {
-            let cgroup_name = cgroups[0].pathname.clone();
-            if cgroup_name.starts_with("/docker") {
-                let container_id = cgroup_name.replace("/docker/", "");
-                if let Some(name) = self.containers.get(&container_id) {
+        let info = proc
+            .curr_proc
+            .cgroups()
+            .ok()
+            .and_then(|x| crate::cgroup::unified_path(&x))
+            .and_then(|x| crate::cgroup::parse_container(&x));
+        let fmt_content = match info {
+            Some(info) if info.runtime == "docker" => {
+                if let Some(name) = self.containers.get(&info.id) {
                     name.to_string()
                 } else {
                     String::from("?")
                 }
-            } else {
-                String::from("")
             }
-        } else {
-            String::from("")
+            _ => String::from(""),
         };
         let raw_content = fmt_content.clone();
 
@@ -0,0 +1,117 @@
+use crate::process::ProcessInfo;
+use crate::Column;
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Env {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    names: Vec<String>,
+    vars: HashMap<i32, Vec<(String, String)>>,
+}
+
+impl Env {
+    pub fn new(header: Option<String>, names: &[String]) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Env"));
+        let unit = String::from("");
+        Env {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            names: names.to_vec(),
+            vars: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for Env {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // environ of other users' processes can't be read without privilege
+        let vars = if let Ok(environ) = proc.curr_proc.environ() {
+            let mut vars: Vec<(String, String)> = environ
+                .iter()
+                .map(|(k, v)| {
+                    (
+                        k.to_string_lossy().to_string(),
+                        v.to_string_lossy().to_string(),
+                    )
+                })
+                .filter(|(k, _)| self.names.is_empty() || self.names.contains(k))
+                .collect();
+            // Variables are shown by the configured order
+            if self.names.is_empty() {
+                vars.sort();
+            } else {
+                vars.sort_by_key(|(k, _)| self.names.iter().position(|x| x == k));
+            }
+            vars
+        } else {
+            Vec::new()
+        };
+        let fmt_content = vars
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+        self.vars.insert(proc.pid, vars);
+    }
+
+    fn find_exact(&self, pid: i32, keyword: &str) -> bool {
+        if let Some(vars) = self.vars.get(&pid) {
+            find_var(vars, keyword)
+        } else {
+            false
+        }
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_find_partial!();
+    crate::column_default_sorted_pid!(String);
+    crate::column_default_apply_visible!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
+
+// The keyword is "NAME=value" or "NAME", and values may contain spaces
+fn find_var(vars: &[(String, String)], keyword: &str) -> bool {
+    vars.iter().any(|(k, v)| {
+        if let Some(value) = keyword.strip_prefix(k.as_str()) {
+            value.is_empty() || value.strip_prefix('=') == Some(v.as_str())
+        } else {
+            false
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_var() {
+        let vars = vec![
+            (String::from("LANG"), String::from("C")),
+            (String::from("OPTS"), String::from("-a -b")),
+        ];
+        assert!(find_var(&vars, "LANG"));
+        assert!(find_var(&vars, "LANG=C"));
+        assert!(find_var(&vars, "OPTS=-a -b"));
+        assert!(!find_var(&vars, "OPTS=-a"));
+        assert!(!find_var(&vars, "-b"));
+        assert!(!find_var(&vars, "LAN"));
+        assert!(!find_var(&vars, "LANG=D"));
+    }
+}
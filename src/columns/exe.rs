@@ -0,0 +1,66 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Exe {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+}
+
+impl Exe {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Executable"));
+        let unit = String::from("");
+        Exe {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for Exe {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // The link target has " (deleted)" suffix if the executable is removed or replaced
+        let fmt_content = if let Ok(x) = proc.curr_proc.exe() {
+            x.to_string_lossy().to_string()
+        } else {
+            String::from("")
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(target_os = "macos")]
+impl Column for Exe {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = if let Ok(x) = libproc::libproc::proc_pid::pidpath(proc.pid) {
+            if std::path::Path::new(&x).exists() {
+                x
+            } else {
+                format!("{} (deleted)", x)
+            }
+        } else {
+            String::from("")
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
@@ -0,0 +1,47 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct FdCount {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+}
+
+impl FdCount {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("FDs"));
+        let unit = String::from("");
+        FdCount {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for FdCount {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let count = match proc.curr_fds {
+            Some(ref fds) => Ok(fds.len()),
+            None => proc.curr_proc.fd_count(),
+        };
+        let (fmt_content, raw_content) = if let Ok(count) = count {
+            let count = count as u64;
+            (format!("{}", count), count)
+        } else {
+            (String::from(""), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u64);
+}
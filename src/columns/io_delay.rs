@@ -0,0 +1,58 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct IoDelay {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, u32>,
+    width: usize,
+}
+
+impl IoDelay {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("IO Delay"));
+        let unit = String::from("[%]");
+        IoDelay {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for IoDelay {
+    fn require_blkio_delay(&self) -> bool {
+        true
+    }
+
+    fn add(&mut self, proc: &ProcessInfo) {
+        // delayacct_blkio_ticks is always 0 if delay accounting is disabled by kernel.task_delayacct.
+        // Swap delay is not included because it is provided by taskstats netlink only.
+        let (fmt_content, raw_content) =
+            if let (Some(curr), Some(prev)) = (proc.curr_blkio_delay, proc.prev_blkio_delay) {
+                let delay_ms = curr.saturating_sub(prev) * 1000
+                    / procfs::ticks_per_second().unwrap_or(100) as u64;
+                let interval_ms =
+                    proc.interval.as_secs() * 1000 + u64::from(proc.interval.subsec_millis());
+                let delay = delay_ms as f64 * 100.0 / interval_ms as f64;
+                (format!("{:.1}", delay), (delay * 1000.0) as u32)
+            } else {
+                (String::from(""), 0)
+            };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn history_value(&self, pid: i32) -> Option<f64> {
+        self.raw_contents.get(&pid).map(|x| *x as f64 / 1000.0)
+    }
+
+    column_default!(u32);
+}
@@ -0,0 +1,60 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct K8sNamespace {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    #[cfg(target_os = "linux")]
+    pods: HashMap<String, (String, String)>,
+}
+
+impl K8sNamespace {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Namespace"));
+        let unit = String::from("");
+        K8sNamespace {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            #[cfg(target_os = "linux")]
+            pods: crate::columns::pod::read_pods(),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for K8sNamespace {
+    fn refresh(&mut self) {
+        self.pods = crate::columns::pod::read_pods();
+    }
+
+    fn add(&mut self, proc: &ProcessInfo) {
+        let path = proc
+            .curr_proc
+            .cgroups()
+            .ok()
+            .and_then(|x| crate::cgroup::unified_path(&x));
+        let info = path.as_ref().and_then(|x| crate::cgroup::parse_pod(x));
+
+        let fmt_content = if let (Some(path), Some(info)) = (path, info) {
+            crate::columns::pod::resolve_pod(&mut self.pods, &path, &info.uid)
+                .map(|(namespace, _)| namespace.clone())
+                .unwrap_or_default()
+        } else {
+            String::from("")
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
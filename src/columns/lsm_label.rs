@@ -0,0 +1,45 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct LsmLabel {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+}
+
+impl LsmLabel {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("LSM Label"));
+        let unit = String::from("");
+        LsmLabel {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for LsmLabel {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // SELinux context or AppArmor profile, which may be terminated by NUL
+        let path = format!("/proc/{}/attr/current", proc.pid);
+        let fmt_content = if let Ok(x) = std::fs::read_to_string(path) {
+            String::from(x.trim_end_matches(['\0', '\n']))
+        } else {
+            String::from("")
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
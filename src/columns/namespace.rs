@@ -0,0 +1,74 @@
+use crate::process::{read_ns, ProcessInfo};
+use crate::Column;
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Namespace {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    ns: &'static str,
+    own: Option<u64>,
+    mark_foreign: bool,
+}
+
+impl Namespace {
+    pub fn new(
+        header: Option<String>,
+        default_header: &str,
+        ns: &'static str,
+        mark_foreign: bool,
+    ) -> Self {
+        let header = header.unwrap_or_else(|| String::from(default_header));
+        let unit = String::from("");
+        Namespace {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            ns,
+            own: read_ns("self", ns),
+            mark_foreign,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for Namespace {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let (fmt_content, raw_content) = if let Some(x) = read_ns(&proc.pid.to_string(), self.ns) {
+            // Processes in the other namespace than procs are marked by "*"
+            if self.mark_foreign && self.own.is_some() && self.own != Some(x) {
+                (format!("{}*", x), x)
+            } else {
+                (format!("{}", x), x)
+            }
+        } else {
+            (String::from(""), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn find_exact(&self, pid: i32, keyword: &str) -> bool {
+        if let Some(content) = self.fmt_contents.get(&pid) {
+            content.trim_end_matches('*') == keyword
+        } else {
+            false
+        }
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_find_partial!();
+    crate::column_default_sorted_pid!(u64);
+    crate::column_default_apply_visible!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
@@ -0,0 +1,43 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct NoNewPrivs {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+}
+
+impl NoNewPrivs {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("NoNewPrivs"));
+        let unit = String::from("");
+        NoNewPrivs {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for NoNewPrivs {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let val = proc.curr_status.as_ref().and_then(|x| x.nonewprivs);
+        let (fmt_content, raw_content) = if let Some(val) = val {
+            (format!("{}", val), val)
+        } else {
+            (String::from(""), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u64);
+}
@@ -0,0 +1,101 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct NumaNode {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, i32>,
+    width: usize,
+    cpu_to_node: HashMap<i32, i32>,
+}
+
+impl NumaNode {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Node"));
+        let unit = String::from("");
+        NumaNode {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            cpu_to_node: read_cpu_to_node(),
+        }
+    }
+}
+
+// Each node has the list of CPUs like "0-3,8-11" in sysfs
+fn read_cpu_to_node() -> HashMap<i32, i32> {
+    let mut ret = HashMap::new();
+    if let Ok(entries) = std::fs::read_dir("/sys/devices/system/node") {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let node = if let Some(Ok(x)) = name.strip_prefix("node").map(|x| x.parse()) {
+                x
+            } else {
+                continue;
+            };
+            let cpulist = std::fs::read_to_string(entry.path().join("cpulist")).unwrap_or_default();
+            for cpu in parse_cpulist(&cpulist) {
+                ret.insert(cpu, node);
+            }
+        }
+    }
+    ret
+}
+
+fn parse_cpulist(s: &str) -> Vec<i32> {
+    let mut ret = Vec::new();
+    for range in s.trim().split(',') {
+        let mut range = range.splitn(2, '-');
+        let start: Option<i32> = range.next().and_then(|x| x.parse().ok());
+        let end = range.next().and_then(|x| x.parse().ok()).or(start);
+        if let (Some(start), Some(end)) = (start, end) {
+            ret.extend(start..=end);
+        }
+    }
+    ret
+}
+
+#[cfg(target_os = "linux")]
+impl Column for NumaNode {
+    fn available(&self) -> bool {
+        !self.cpu_to_node.is_empty()
+    }
+
+    fn refresh(&mut self) {
+        self.cpu_to_node = read_cpu_to_node();
+    }
+
+    fn add(&mut self, proc: &ProcessInfo) {
+        let node = proc
+            .curr_proc
+            .stat
+            .processor
+            .and_then(|x| self.cpu_to_node.get(&x));
+        let (fmt_content, raw_content) = if let Some(node) = node {
+            (format!("{}", node), *node)
+        } else {
+            (String::from(""), -1)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(i32);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpulist() {
+        assert_eq!(parse_cpulist(""), Vec::<i32>::new());
+        assert_eq!(parse_cpulist("0-3,8\n"), [0, 1, 2, 3, 8]);
+    }
+}
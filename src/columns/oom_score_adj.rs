@@ -0,0 +1,46 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct OomScoreAdj {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, i32>,
+    width: usize,
+}
+
+impl OomScoreAdj {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("OOM Adj"));
+        let unit = String::from("");
+        OomScoreAdj {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for OomScoreAdj {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let path = format!("/proc/{}/oom_score_adj", proc.pid);
+        let value = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|x| x.trim().parse::<i32>().ok());
+        let (fmt_content, raw_content) = if let Some(x) = value {
+            (format!("{}", x), x)
+        } else {
+            (String::from(""), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(i32);
+}
@@ -0,0 +1,76 @@
+use crate::process::ProcessInfo;
+use crate::Column;
+#[cfg(target_os = "linux")]
+use procfs::process::FDTarget;
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct OpenFiles {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+}
+
+impl OpenFiles {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Open Files"));
+        let unit = String::from("");
+        OpenFiles {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for OpenFiles {
+    fn require_fd(&self) -> bool {
+        true
+    }
+
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = if let Some(ref fds) = proc.curr_fds {
+            let mut files = Vec::new();
+            for fd in fds {
+                if let FDTarget::Path(ref x) = fd.target {
+                    // device files like /dev/null and terminals are not listed
+                    if !x.starts_with("/dev") {
+                        files.push(x.to_string_lossy().to_string());
+                    }
+                }
+            }
+            files.sort();
+            files.dedup();
+            files.join(", ")
+        } else {
+            String::from("")
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn find_exact(&self, pid: i32, keyword: &str) -> bool {
+        if let Some(content) = self.fmt_contents.get(&pid) {
+            content.split(", ").any(|x| x == keyword)
+        } else {
+            false
+        }
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_find_partial!();
+    crate::column_default_sorted_pid!(String);
+    crate::column_default_apply_visible!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
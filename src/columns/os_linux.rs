@@ -1,11 +1,21 @@
+pub mod allowed_list;
+pub mod capability;
+pub mod cgroup;
+pub mod cgroup_value;
 pub mod command;
+pub mod container;
 pub mod context_sw;
 pub mod cpu_time;
+pub mod ctx_sw;
+pub mod cwd;
 #[cfg(feature = "docker")]
 pub mod docker;
 pub mod eip;
 pub mod empty;
+pub mod env;
 pub mod esp;
+pub mod exe;
+pub mod fd_count;
 pub mod gid;
 pub mod gid_fs;
 pub mod gid_real;
@@ -14,26 +24,43 @@ pub mod group;
 pub mod group_fs;
 pub mod group_real;
 pub mod group_saved;
+pub mod io_delay;
+pub mod k8s_namespace;
+pub mod lsm_label;
 pub mod maj_flt;
 pub mod min_flt;
+pub mod namespace;
 pub mod nice;
+pub mod no_new_privs;
+pub mod numa_node;
+pub mod oom_score;
+pub mod oom_score_adj;
+pub mod open_files;
 pub mod pid;
+pub mod pod;
 pub mod policy;
 pub mod ppid;
 pub mod priority;
 pub mod processor;
 pub mod read_bytes;
 pub mod rt_priority;
+pub mod sched_rate;
+pub mod seccomp;
 pub mod separator;
 pub mod shd_pnd;
 pub mod sig_blk;
 pub mod sig_cgt;
 pub mod sig_ign;
 pub mod sig_pnd;
+pub mod slice;
 pub mod slot;
+pub mod smaps;
+pub mod sparkline;
 pub mod ssb;
 pub mod start_time;
 pub mod state;
+pub mod syscall;
+pub mod tcp_conn;
 pub mod tcp_port;
 pub mod threads;
 pub mod tree;
@@ -44,6 +71,8 @@ pub mod uid_fs;
 pub mod uid_login;
 pub mod uid_real;
 pub mod uid_saved;
+pub mod unit;
+pub mod unix_socket;
 pub mod usage_cpu;
 pub mod usage_mem;
 pub mod user;
@@ -66,14 +95,24 @@ pub mod vm_swap;
 pub mod wchan;
 pub mod write_bytes;
 
+pub use self::allowed_list::AllowedList;
+pub use self::capability::Capability;
+pub use self::cgroup::Cgroup;
+pub use self::cgroup_value::CgroupValue;
 pub use self::command::Command;
+pub use self::container::Container;
 pub use self::context_sw::ContextSw;
 pub use self::cpu_time::CpuTime;
+pub use self::ctx_sw::CtxSw;
+pub use self::cwd::Cwd;
 #[cfg(feature = "docker")]
 pub use self::docker::Docker;
 pub use self::eip::Eip;
 pub use self::empty::Empty;
+pub use self::env::Env;
 pub use self::esp::Esp;
+pub use self::exe::Exe;
+pub use self::fd_count::FdCount;
 pub use self::gid::Gid;
 pub use self::gid_fs::GidFs;
 pub use self::gid_real::GidReal;
@@ -82,26 +121,43 @@ pub use self::group::Group;
 pub use self::group_fs::GroupFs;
 pub use self::group_real::GroupReal;
 pub use self::group_saved::GroupSaved;
+pub use self::io_delay::IoDelay;
+pub use self::k8s_namespace::K8sNamespace;
+pub use self::lsm_label::LsmLabel;
 pub use self::maj_flt::MajFlt;
 pub use self::min_flt::MinFlt;
+pub use self::namespace::Namespace;
 pub use self::nice::Nice;
+pub use self::no_new_privs::NoNewPrivs;
+pub use self::numa_node::NumaNode;
+pub use self::oom_score::OomScore;
+pub use self::oom_score_adj::OomScoreAdj;
+pub use self::open_files::OpenFiles;
 pub use self::pid::Pid;
+pub use self::pod::Pod;
 pub use self::policy::Policy;
 pub use self::ppid::Ppid;
 pub use self::priority::Priority;
 pub use self::processor::Processor;
 pub use self::read_bytes::ReadBytes;
 pub use self::rt_priority::RtPriority;
+pub use self::sched_rate::SchedRate;
+pub use self::seccomp::Seccomp;
 pub use self::separator::Separator;
 pub use self::shd_pnd::ShdPnd;
 pub use self::sig_blk::SigBlk;
 pub use self::sig_cgt::SigCgt;
 pub use self::sig_ign::SigIgn;
 pub use self::sig_pnd::SigPnd;
+pub use self::slice::Slice;
 pub use self::slot::Slot;
+pub use self::smaps::Smaps;
+pub use self::sparkline::Sparkline;
 pub use self::ssb::Ssb;
 pub use self::start_time::StartTime;
 pub use self::state::State;
+pub use self::syscall::Syscall;
+pub use self::tcp_conn::TcpConn;
 pub use self::tcp_port::TcpPort;
 pub use self::threads::Threads;
 pub use self::tree::Tree;
@@ -112,6 +168,8 @@ pub use self::uid_fs::UidFs;
 pub use self::uid_login::UidLogin;
 pub use self::uid_real::UidReal;
 pub use self::uid_saved::UidSaved;
+pub use self::unit::Unit;
+pub use self::unix_socket::UnixSocket;
 pub use self::usage_cpu::UsageCpu;
 pub use self::usage_mem::UsageMem;
 pub use self::user::User;
@@ -145,13 +203,29 @@ use std::collections::HashMap;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ConfigColumnKind {
+    CapAmb,
+    CapBnd,
+    CapEff,
+    CapPrm,
+    Cgroup,
+    CgroupMem,
+    CgroupMemMax,
+    CgroupPids,
     Command,
+    Container,
     ContextSw,
+    CpuHistory,
+    CpuThrottled,
     CpuTime,
+    CpusAllowed,
+    Cwd,
     Docker,
     Eip,
     Empty,
+    Env,
     Esp,
+    Exe,
+    FdCount,
     Gid,
     GidFs,
     GidReal,
@@ -160,18 +234,47 @@ pub enum ConfigColumnKind {
     GroupFs,
     GroupReal,
     GroupSaved,
+    IoDelay,
+    K8sNamespace,
+    LsmLabel,
     MajFlt,
+    MemHistory,
+    MemsAllowed,
     MinFlt,
     Nice,
+    NoNewPrivs,
+    NonvolCtxSw,
+    NsCgroup,
+    NsIpc,
+    NsMnt,
+    NsNet,
+    NsPid,
+    NsUser,
+    NsUts,
+    NumaNode,
+    OomScore,
+    OomScoreAdj,
+    OpenFiles,
     Pid,
+    Pod,
     Policy,
     Ppid,
     Priority,
     Processor,
+    Pss,
     ReadBytes,
+    ReadHistory,
+    RssHistory,
     RtPriority,
+    SchedRun,
+    SchedSlices,
+    SchedWait,
+    Seccomp,
     Separator,
+    SharedClean,
+    SharedDirty,
     ShdPnd,
+    Slice,
     Slot,
     SigBlk,
     SigCgt,
@@ -180,6 +283,9 @@ pub enum ConfigColumnKind {
     Ssb,
     StartTime,
     State,
+    SwapPss,
+    Syscall,
+    TcpConn,
     TcpPort,
     Threads,
     Tree,
@@ -190,6 +296,8 @@ pub enum ConfigColumnKind {
     UidLogin,
     UidReal,
     UidSaved,
+    Unit,
+    UnixSocket,
     UsageCpu,
     UsageMem,
     User,
@@ -198,6 +306,7 @@ pub enum ConfigColumnKind {
     UserReal,
     UserSaved,
     Username,
+    Uss,
     VmData,
     VmExe,
     VmHwm,
@@ -210,14 +319,17 @@ pub enum ConfigColumnKind {
     VmSize,
     VmStack,
     VmSwap,
+    VolCtxSw,
     Wchan,
     WriteBytes,
+    WriteHistory,
 }
 
 // ---------------------------------------------------------------------------------------------------------------------
 // gen_column
 // ---------------------------------------------------------------------------------------------------------------------
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_column(
     kind: &ConfigColumnKind,
     header: Option<String>,
@@ -225,18 +337,76 @@ pub fn gen_column(
     separator: &str,
     abbr_sid: bool,
     tree_symbols: &[String; 5],
+    env: &[String],
+    resolve_container_name: bool,
+    mark_foreign_ns: bool,
+    decode_signal: bool,
+    rate: bool,
 ) -> Box<dyn Column> {
     match kind {
+        ConfigColumnKind::CapAmb => Box::new(Capability::new(header, "CapAmb", |x| x.capamb)),
+        ConfigColumnKind::CapBnd => Box::new(Capability::new(header, "CapBnd", |x| x.capbnd)),
+        ConfigColumnKind::CapEff => Box::new(Capability::new(header, "CapEff", |x| Some(x.capeff))),
+        ConfigColumnKind::CapPrm => Box::new(Capability::new(header, "CapPrm", |x| Some(x.capprm))),
+        ConfigColumnKind::Cgroup => Box::new(Cgroup::new(header)),
+        ConfigColumnKind::CgroupMem => Box::new(CgroupValue::new(
+            header,
+            "Cgroup Mem",
+            "[bytes]",
+            "memory.current",
+            None,
+            true,
+        )),
+        ConfigColumnKind::CgroupMemMax => Box::new(CgroupValue::new(
+            header,
+            "Cgroup MemMax",
+            "[bytes]",
+            "memory.max",
+            None,
+            true,
+        )),
+        ConfigColumnKind::CgroupPids => Box::new(CgroupValue::new(
+            header,
+            "Cgroup Pids",
+            "",
+            "pids.current",
+            None,
+            false,
+        )),
         ConfigColumnKind::Command => Box::new(Command::new(header)),
+        ConfigColumnKind::Container => Box::new(Container::new(header, resolve_container_name)),
         ConfigColumnKind::ContextSw => Box::new(ContextSw::new(header)),
+        ConfigColumnKind::CpuHistory => Box::new(Sparkline::new(
+            header,
+            "CPU History",
+            "[%]",
+            Box::new(UsageCpu::new(None)),
+            100.0,
+            false,
+        )),
+        ConfigColumnKind::CpuThrottled => Box::new(CgroupValue::new(
+            header,
+            "Throttled",
+            "",
+            "cpu.stat",
+            Some("nr_throttled"),
+            false,
+        )),
         ConfigColumnKind::CpuTime => Box::new(CpuTime::new(header)),
+        ConfigColumnKind::CpusAllowed => Box::new(AllowedList::new(header, "CPUs", |x| {
+            x.cpus_allowed_list.as_ref()
+        })),
+        ConfigColumnKind::Cwd => Box::new(Cwd::new(header)),
         #[cfg(feature = "docker")]
         ConfigColumnKind::Docker => Box::new(Docker::new(header, _docker_path)),
         #[cfg(not(feature = "docker"))]
         ConfigColumnKind::Docker => Box::new(Empty::new()),
         ConfigColumnKind::Eip => Box::new(Eip::new(header)),
         ConfigColumnKind::Empty => Box::new(Empty::new()),
+        ConfigColumnKind::Env => Box::new(Env::new(header, env)),
         ConfigColumnKind::Esp => Box::new(Esp::new(header)),
+        ConfigColumnKind::Exe => Box::new(Exe::new(header)),
+        ConfigColumnKind::FdCount => Box::new(FdCount::new(header)),
         ConfigColumnKind::Gid => Box::new(Gid::new(header, abbr_sid)),
         ConfigColumnKind::GidFs => Box::new(GidFs::new(header)),
         ConfigColumnKind::GidReal => Box::new(GidReal::new(header)),
@@ -245,26 +415,113 @@ pub fn gen_column(
         ConfigColumnKind::GroupFs => Box::new(GroupFs::new(header)),
         ConfigColumnKind::GroupReal => Box::new(GroupReal::new(header)),
         ConfigColumnKind::GroupSaved => Box::new(GroupSaved::new(header)),
+        ConfigColumnKind::IoDelay => Box::new(IoDelay::new(header)),
+        ConfigColumnKind::K8sNamespace => Box::new(K8sNamespace::new(header)),
+        ConfigColumnKind::LsmLabel => Box::new(LsmLabel::new(header)),
         ConfigColumnKind::MajFlt => Box::new(MajFlt::new(header)),
+        ConfigColumnKind::MemHistory => Box::new(Sparkline::new(
+            header,
+            "MEM History",
+            "[%]",
+            Box::new(UsageMem::new(None)),
+            100.0,
+            false,
+        )),
+        ConfigColumnKind::MemsAllowed => Box::new(AllowedList::new(header, "Mems", |x| {
+            x.mems_allowed_list.as_ref()
+        })),
         ConfigColumnKind::MinFlt => Box::new(MinFlt::new(header)),
         ConfigColumnKind::Nice => Box::new(Nice::new(header)),
+        ConfigColumnKind::NoNewPrivs => Box::new(NoNewPrivs::new(header)),
+        ConfigColumnKind::NonvolCtxSw => Box::new(CtxSw::new(
+            header,
+            "NonvolCtxSw",
+            |x| x.nonvoluntary_ctxt_switches,
+            rate,
+        )),
+        ConfigColumnKind::NsCgroup => Box::new(Namespace::new(
+            header,
+            "Cgroup NS",
+            "cgroup",
+            mark_foreign_ns,
+        )),
+        ConfigColumnKind::NsIpc => {
+            Box::new(Namespace::new(header, "IPC NS", "ipc", mark_foreign_ns))
+        }
+        ConfigColumnKind::NsMnt => {
+            Box::new(Namespace::new(header, "MNT NS", "mnt", mark_foreign_ns))
+        }
+        ConfigColumnKind::NsNet => {
+            Box::new(Namespace::new(header, "NET NS", "net", mark_foreign_ns))
+        }
+        ConfigColumnKind::NsPid => {
+            Box::new(Namespace::new(header, "PID NS", "pid", mark_foreign_ns))
+        }
+        ConfigColumnKind::NsUser => {
+            Box::new(Namespace::new(header, "USER NS", "user", mark_foreign_ns))
+        }
+        ConfigColumnKind::NsUts => {
+            Box::new(Namespace::new(header, "UTS NS", "uts", mark_foreign_ns))
+        }
+        ConfigColumnKind::NumaNode => Box::new(NumaNode::new(header)),
+        ConfigColumnKind::OomScore => Box::new(OomScore::new(header)),
+        ConfigColumnKind::OomScoreAdj => Box::new(OomScoreAdj::new(header)),
+        ConfigColumnKind::OpenFiles => Box::new(OpenFiles::new(header)),
         ConfigColumnKind::Pid => Box::new(Pid::new(header)),
+        ConfigColumnKind::Pod => Box::new(Pod::new(header)),
         ConfigColumnKind::Policy => Box::new(Policy::new(header)),
         ConfigColumnKind::Ppid => Box::new(Ppid::new(header)),
         ConfigColumnKind::Priority => Box::new(Priority::new(header)),
         ConfigColumnKind::Processor => Box::new(Processor::new(header)),
+        ConfigColumnKind::Pss => Box::new(Smaps::new(header, "PSS", &["Pss"])),
         ConfigColumnKind::ReadBytes => Box::new(ReadBytes::new(header)),
+        ConfigColumnKind::ReadHistory => Box::new(Sparkline::new(
+            header,
+            "Read History",
+            "[B/s]",
+            Box::new(ReadBytes::new(None)),
+            0.0,
+            true,
+        )),
+        ConfigColumnKind::RssHistory => Box::new(Sparkline::new(
+            header,
+            "RSS History",
+            "[bytes]",
+            Box::new(VmRss::new(None)),
+            0.0,
+            true,
+        )),
         ConfigColumnKind::RtPriority => Box::new(RtPriority::new(header)),
+        ConfigColumnKind::SchedRun => {
+            Box::new(SchedRate::new(header, "Run", |x| x.sum_exec_runtime, true))
+        }
+        ConfigColumnKind::SchedSlices => {
+            Box::new(SchedRate::new(header, "Slices", |x| x.pcount, false))
+        }
+        ConfigColumnKind::SchedWait => {
+            Box::new(SchedRate::new(header, "Wait", |x| x.run_delay, true))
+        }
+        ConfigColumnKind::Seccomp => Box::new(Seccomp::new(header)),
         ConfigColumnKind::Separator => Box::new(Separator::new(separator)),
-        ConfigColumnKind::ShdPnd => Box::new(ShdPnd::new(header)),
+        ConfigColumnKind::SharedClean => {
+            Box::new(Smaps::new(header, "Shared Clean", &["Shared_Clean"]))
+        }
+        ConfigColumnKind::SharedDirty => {
+            Box::new(Smaps::new(header, "Shared Dirty", &["Shared_Dirty"]))
+        }
+        ConfigColumnKind::ShdPnd => Box::new(ShdPnd::new(header, decode_signal)),
+        ConfigColumnKind::Slice => Box::new(Slice::new(header)),
         ConfigColumnKind::Slot => Box::new(Slot::new()),
-        ConfigColumnKind::SigBlk => Box::new(SigBlk::new(header)),
-        ConfigColumnKind::SigCgt => Box::new(SigCgt::new(header)),
-        ConfigColumnKind::SigIgn => Box::new(SigIgn::new(header)),
-        ConfigColumnKind::SigPnd => Box::new(SigPnd::new(header)),
+        ConfigColumnKind::SigBlk => Box::new(SigBlk::new(header, decode_signal)),
+        ConfigColumnKind::SigCgt => Box::new(SigCgt::new(header, decode_signal)),
+        ConfigColumnKind::SigIgn => Box::new(SigIgn::new(header, decode_signal)),
+        ConfigColumnKind::SigPnd => Box::new(SigPnd::new(header, decode_signal)),
         ConfigColumnKind::Ssb => Box::new(Ssb::new(header)),
         ConfigColumnKind::StartTime => Box::new(StartTime::new(header)),
         ConfigColumnKind::State => Box::new(State::new(header)),
+        ConfigColumnKind::SwapPss => Box::new(Smaps::new(header, "Swap PSS", &["SwapPss"])),
+        ConfigColumnKind::Syscall => Box::new(Syscall::new(header)),
+        ConfigColumnKind::TcpConn => Box::new(TcpConn::new(header)),
         ConfigColumnKind::TcpPort => Box::new(TcpPort::new(header)),
         ConfigColumnKind::Threads => Box::new(Threads::new(header)),
         ConfigColumnKind::Tree => Box::new(Tree::new(tree_symbols)),
@@ -275,6 +532,8 @@ pub fn gen_column(
         ConfigColumnKind::UidLogin => Box::new(UidLogin::new(header)),
         ConfigColumnKind::UidReal => Box::new(UidReal::new(header)),
         ConfigColumnKind::UidSaved => Box::new(UidSaved::new(header)),
+        ConfigColumnKind::Unit => Box::new(Unit::new(header)),
+        ConfigColumnKind::UnixSocket => Box::new(UnixSocket::new(header)),
         ConfigColumnKind::UsageCpu => Box::new(UsageCpu::new(header)),
         ConfigColumnKind::UsageMem => Box::new(UsageMem::new(header)),
         ConfigColumnKind::User => Box::new(User::new(header, abbr_sid)),
@@ -283,6 +542,11 @@ pub fn gen_column(
         ConfigColumnKind::UserReal => Box::new(UserReal::new(header)),
         ConfigColumnKind::UserSaved => Box::new(UserSaved::new(header)),
         ConfigColumnKind::Username => Box::new(User::new(header, abbr_sid)),
+        ConfigColumnKind::Uss => Box::new(Smaps::new(
+            header,
+            "USS",
+            &["Private_Clean", "Private_Dirty"],
+        )),
         ConfigColumnKind::VmData => Box::new(VmData::new(header)),
         ConfigColumnKind::VmExe => Box::new(VmExe::new(header)),
         ConfigColumnKind::VmHwm => Box::new(VmHwm::new(header)),
@@ -295,8 +559,22 @@ pub fn gen_column(
         ConfigColumnKind::VmSize => Box::new(VmSize::new(header)),
         ConfigColumnKind::VmStack => Box::new(VmStack::new(header)),
         ConfigColumnKind::VmSwap => Box::new(VmSwap::new(header)),
+        ConfigColumnKind::VolCtxSw => Box::new(CtxSw::new(
+            header,
+            "VolCtxSw",
+            |x| x.voluntary_ctxt_switches,
+            rate,
+        )),
         ConfigColumnKind::Wchan => Box::new(Wchan::new(header)),
         ConfigColumnKind::WriteBytes => Box::new(WriteBytes::new(header)),
+        ConfigColumnKind::WriteHistory => Box::new(Sparkline::new(
+            header,
+            "Write History",
+            "[B/s]",
+            Box::new(WriteBytes::new(None)),
+            0.0,
+            true,
+        )),
     }
 }
 
@@ -306,25 +584,74 @@ pub fn gen_column(
 
 lazy_static! {
     pub static ref KIND_LIST: HashMap<ConfigColumnKind, (&'static str, &'static str)> = [
+        (ConfigColumnKind::CapAmb, ("CapAmb", "Ambient capabilities")),
+        (
+            ConfigColumnKind::CapBnd,
+            ("CapBnd", "Bounding capabilities")
+        ),
+        (
+            ConfigColumnKind::CapEff,
+            ("CapEff", "Effective capabilities")
+        ),
+        (
+            ConfigColumnKind::CapPrm,
+            ("CapPrm", "Permitted capabilities")
+        ),
+        (ConfigColumnKind::Cgroup, ("Cgroup", "Cgroup path")),
+        (
+            ConfigColumnKind::CgroupMem,
+            ("CgroupMem", "Cgroup memory usage")
+        ),
+        (
+            ConfigColumnKind::CgroupMemMax,
+            ("CgroupMemMax", "Cgroup memory limit")
+        ),
+        (
+            ConfigColumnKind::CgroupPids,
+            ("CgroupPids", "Cgroup process count")
+        ),
         (
             ConfigColumnKind::Command,
             ("Command", "Command with all arguments")
         ),
+        (
+            ConfigColumnKind::Container,
+            ("Container", "Container runtime and ID")
+        ),
         (
             ConfigColumnKind::ContextSw,
             ("ContextSw", "Context switch count")
         ),
+        (
+            ConfigColumnKind::CpuHistory,
+            ("CpuHistory", "CPU utilization history")
+        ),
+        (
+            ConfigColumnKind::CpuThrottled,
+            ("CpuThrottled", "Cgroup CPU throttled count")
+        ),
         (
             ConfigColumnKind::CpuTime,
             ("CpuTime", "Cumulative CPU time")
         ),
+        (
+            ConfigColumnKind::CpusAllowed,
+            ("CpusAllowed", "Allowed CPUs")
+        ),
+        (ConfigColumnKind::Cwd, ("Cwd", "Current working directory")),
         (
             ConfigColumnKind::Docker,
             ("Docker", "Docker container name")
         ),
         (ConfigColumnKind::Eip, ("Eip", "Instruction pointer")),
         (ConfigColumnKind::Empty, ("Empty", "Empty")),
+        (ConfigColumnKind::Env, ("Env", "Environment variables")),
         (ConfigColumnKind::Esp, ("Esp", "Stack pointer")),
+        (ConfigColumnKind::Exe, ("Exe", "Executable path")),
+        (
+            ConfigColumnKind::FdCount,
+            ("FdCount", "File descriptor count")
+        ),
         (ConfigColumnKind::Gid, ("Gid", "Group ID")),
         (ConfigColumnKind::GidFs, ("GidFs", "File system group ID")),
         (ConfigColumnKind::GidReal, ("GidReal", "Real group ID")),
@@ -342,16 +669,68 @@ lazy_static! {
             ConfigColumnKind::GroupSaved,
             ("GroupSaved", "Saved group name")
         ),
+        (
+            ConfigColumnKind::IoDelay,
+            ("IoDelay", "Block IO delay of all threads")
+        ),
+        (
+            ConfigColumnKind::K8sNamespace,
+            ("K8sNamespace", "Kubernetes namespace")
+        ),
+        (
+            ConfigColumnKind::LsmLabel,
+            ("LsmLabel", "LSM security label")
+        ),
         (
             ConfigColumnKind::MajFlt,
             ("MajFlt", "Major page fault count")
         ),
+        (
+            ConfigColumnKind::MemHistory,
+            ("MemHistory", "Memory utilization history")
+        ),
+        (
+            ConfigColumnKind::MemsAllowed,
+            ("MemsAllowed", "Allowed memory nodes")
+        ),
         (
             ConfigColumnKind::MinFlt,
             ("MinFlt", "Minor page fault count")
         ),
         (ConfigColumnKind::Nice, ("Nice", "Nice value")),
+        (
+            ConfigColumnKind::NoNewPrivs,
+            ("NoNewPrivs", "No new privileges flag")
+        ),
+        (
+            ConfigColumnKind::NonvolCtxSw,
+            ("NonvolCtxSw", "Nonvoluntary context switch")
+        ),
+        (ConfigColumnKind::NsCgroup, ("NsCgroup", "Cgroup namespace")),
+        (ConfigColumnKind::NsIpc, ("NsIpc", "IPC namespace")),
+        (ConfigColumnKind::NsMnt, ("NsMnt", "Mount namespace")),
+        (ConfigColumnKind::NsNet, ("NsNet", "Network namespace")),
+        (ConfigColumnKind::NsPid, ("NsPid", "PID namespace")),
+        (ConfigColumnKind::NsUser, ("NsUser", "User namespace")),
+        (ConfigColumnKind::NsUts, ("NsUts", "UTS namespace")),
+        (
+            ConfigColumnKind::NumaNode,
+            ("NumaNode", "NUMA node of the last used CPU")
+        ),
+        (ConfigColumnKind::OomScore, ("OomScore", "OOM killer score")),
+        (
+            ConfigColumnKind::OomScoreAdj,
+            ("OomScoreAdj", "OOM killer score adjustment")
+        ),
+        (
+            ConfigColumnKind::OpenFiles,
+            ("OpenFiles", "Open regular files")
+        ),
         (ConfigColumnKind::Pid, ("Pid", "Process ID")),
+        (
+            ConfigColumnKind::Pod,
+            ("Pod", "Kubernetes pod name and QoS")
+        ),
         (ConfigColumnKind::Policy, ("Policy", "Scheduling policy")),
         (ConfigColumnKind::Ppid, ("Ppid", "Parent process ID")),
         (ConfigColumnKind::Priority, ("Priority", "Priority")),
@@ -359,22 +738,53 @@ lazy_static! {
             ConfigColumnKind::Processor,
             ("Processor", "Currently assigned processor")
         ),
+        (ConfigColumnKind::Pss, ("Pss", "Proportional set size")),
         (
             ConfigColumnKind::ReadBytes,
             ("ReadBytes", "Read bytes from storage")
         ),
+        (
+            ConfigColumnKind::ReadHistory,
+            ("ReadHistory", "Read bytes history")
+        ),
+        (
+            ConfigColumnKind::RssHistory,
+            ("RssHistory", "Resident set size history")
+        ),
         (
             ConfigColumnKind::RtPriority,
             ("RtPriority", "Real-time priority")
         ),
+        (
+            ConfigColumnKind::SchedRun,
+            ("SchedRun", "Run time on CPU of all threads")
+        ),
+        (
+            ConfigColumnKind::SchedSlices,
+            ("SchedSlices", "Timeslices of all threads")
+        ),
+        (
+            ConfigColumnKind::SchedWait,
+            ("SchedWait", "Run queue wait of all threads")
+        ),
+        (ConfigColumnKind::Seccomp, ("Seccomp", "Seccomp mode")),
         (
             ConfigColumnKind::Separator,
             ("Separator", "Show | for column separation")
         ),
+        (
+            ConfigColumnKind::SharedClean,
+            ("SharedClean", "Shared clean pages size")
+        ),
+        (
+            ConfigColumnKind::SharedDirty,
+            ("SharedDirty", "Shared dirty pages size")
+        ),
         (
             ConfigColumnKind::ShdPnd,
             ("ShdPnd", "Pending signal mask for process")
         ),
+        (ConfigColumnKind::Slice, ("Slice", "Systemd slice")),
         (
             ConfigColumnKind::Slot,
             ("Slot", "Slot for `--insert` option")
@@ -392,6 +802,18 @@ lazy_static! {
         ),
         (ConfigColumnKind::StartTime, ("StartTime", "Starting time")),
         (ConfigColumnKind::State, ("State", "Process state")),
+        (
+            ConfigColumnKind::SwapPss,
+            ("SwapPss", "Proportional swap size")
+        ),
+        (
+            ConfigColumnKind::Syscall,
+            ("Syscall", "Current system call")
+        ),
+        (
+            ConfigColumnKind::TcpConn,
+            ("TcpConn", "Established TCP connections")
+        ),
         (ConfigColumnKind::TcpPort, ("TcpPort", "Bound TCP ports")),
         (ConfigColumnKind::Threads, ("Threads", "Thread count")),
         (ConfigColumnKind::Tty, ("Tty", "Controlling TTY")),
@@ -401,6 +823,11 @@ lazy_static! {
         (ConfigColumnKind::UidLogin, ("UidLogin", "Login user ID")),
         (ConfigColumnKind::UidReal, ("UidReal", "Real user ID")),
         (ConfigColumnKind::UidSaved, ("UidSaved", "Saved user ID")),
+        (ConfigColumnKind::Unit, ("Unit", "Systemd unit")),
+        (
+            ConfigColumnKind::UnixSocket,
+            ("UnixSocket", "Bound unix domain socket paths")
+        ),
         (ConfigColumnKind::UsageCpu, ("UsageCpu", "CPU utilization")),
         (
             ConfigColumnKind::UsageMem,
@@ -420,6 +847,7 @@ lazy_static! {
             ConfigColumnKind::UserSaved,
             ("UserSaved", "Saved user name")
         ),
+        (ConfigColumnKind::Uss, ("Uss", "Unique set size")),
         (ConfigColumnKind::VmData, ("VmData", "Data size")),
         (ConfigColumnKind::VmExe, ("VmExe", "Text segments size")),
         (ConfigColumnKind::VmHwm, ("VmHwm", "Peak resident set size")),
@@ -441,6 +869,10 @@ lazy_static! {
             ConfigColumnKind::VmSwap,
             ("VmSwap", "Swapped-out virtual memory size")
         ),
+        (
+            ConfigColumnKind::VolCtxSw,
+            ("VolCtxSw", "Voluntary context switch")
+        ),
         (
             ConfigColumnKind::Wchan,
             ("Wchan", "Process sleeping kernel function")
@@ -449,6 +881,10 @@ lazy_static! {
             ConfigColumnKind::WriteBytes,
             ("WriteBytes", "Write bytes to storage")
         ),
+        (
+            ConfigColumnKind::WriteHistory,
+            ("WriteHistory", "Write bytes history")
+        ),
     ]
     .iter()
     .cloned()
@@ -513,21 +949,59 @@ nonnumeric_search = true
 // CONFIG_ALL
 // ---------------------------------------------------------------------------------------------------------------------
 
-#[cfg(test)]
 pub static CONFIG_ALL: &str = r#"
 [[columns]]
+kind = "CapAmb"
+style = "White"
+[[columns]]
+kind = "CapBnd"
+style = "White"
+[[columns]]
+kind = "CapEff"
+style = "White"
+[[columns]]
+kind = "CapPrm"
+style = "White"
+[[columns]]
+kind = "Cgroup"
+style = "White"
+[[columns]]
+kind = "CgroupMem"
+style = "White"
+[[columns]]
+kind = "CgroupMemMax"
+style = "White"
+[[columns]]
+kind = "CgroupPids"
+style = "White"
+[[columns]]
 kind = "Command"
 style = "BrightRed"
 align = "Left"
 [[columns]]
+kind = "Container"
+style = "White"
+[[columns]]
 kind = "ContextSw"
 style = "BrightRed"
 align = "Right"
 [[columns]]
+kind = "CpuHistory"
+style = "White"
+[[columns]]
+kind = "CpuThrottled"
+style = "White"
+[[columns]]
 kind = "CpuTime"
 style = "BrightGreen"
 align = "Center"
 [[columns]]
+kind = "CpusAllowed"
+style = "White"
+[[columns]]
+kind = "Cwd"
+style = "White"
+[[columns]]
 kind = "Docker"
 style = "BrightMagenta"
 [[columns]]
@@ -537,9 +1011,18 @@ style = "BrightYellow"
 kind = "Empty"
 style = "BrightYellow"
 [[columns]]
+kind = "Env"
+style = "White"
+[[columns]]
 kind = "Esp"
 style = "BrightBlue"
 [[columns]]
+kind = "Exe"
+style = "White"
+[[columns]]
+kind = "FdCount"
+style = "White"
+[[columns]]
 kind = "Gid"
 style = "White"
 [[columns]]
@@ -564,18 +1047,75 @@ style = "White"
 kind = "GroupSaved"
 style = "White"
 [[columns]]
+kind = "IoDelay"
+style = "ByPercentage"
+[[columns]]
+kind = "K8sNamespace"
+style = "White"
+[[columns]]
+kind = "LsmLabel"
+style = "White"
+[[columns]]
 kind = "MajFlt"
 style = "BrightCyan"
 [[columns]]
+kind = "MemHistory"
+style = "White"
+[[columns]]
+kind = "MemsAllowed"
+style = "White"
+[[columns]]
 kind = "MinFlt"
 style = "BrightWhite"
 [[columns]]
 kind = "Nice"
 style = "Red"
 [[columns]]
+kind = "NoNewPrivs"
+style = "White"
+[[columns]]
+kind = "NonvolCtxSw"
+style = "White"
+[[columns]]
+kind = "NsCgroup"
+style = "White"
+[[columns]]
+kind = "NsIpc"
+style = "White"
+[[columns]]
+kind = "NsMnt"
+style = "White"
+[[columns]]
+kind = "NsNet"
+style = "White"
+[[columns]]
+kind = "NsPid"
+style = "White"
+[[columns]]
+kind = "NsUser"
+style = "White"
+[[columns]]
+kind = "NsUts"
+style = "White"
+[[columns]]
+kind = "NumaNode"
+style = "White"
+[[columns]]
+kind = "OomScore"
+style = "ByScore"
+[[columns]]
+kind = "OomScoreAdj"
+style = "ByScore"
+[[columns]]
+kind = "OpenFiles"
+style = "White"
+[[columns]]
 kind = "Pid"
 style = "Green"
 [[columns]]
+kind = "Pod"
+style = "White"
+[[columns]]
 kind = "Policy"
 style = "Green"
 [[columns]]
@@ -588,15 +1128,42 @@ style = "Blue"
 kind = "Processor"
 style = "Magenta"
 [[columns]]
+kind = "Pss"
+style = "ByUnit"
+[[columns]]
 kind = "ReadBytes"
 style = "Cyan"
 [[columns]]
+kind = "ReadHistory"
+style = "White"
+[[columns]]
+kind = "RssHistory"
+style = "White"
+[[columns]]
 kind = "RtPriority"
 style = "White"
 [[columns]]
+kind = "SchedRun"
+style = "ByPercentage"
+[[columns]]
+kind = "SchedSlices"
+style = "White"
+[[columns]]
+kind = "SchedWait"
+style = "ByPercentage"
+[[columns]]
+kind = "Seccomp"
+style = "White"
+[[columns]]
 kind = "Separator"
 style = "White"
 [[columns]]
+kind = "SharedClean"
+style = "ByUnit"
+[[columns]]
+kind = "SharedDirty"
+style = "ByUnit"
+[[columns]]
 kind = "ShdPnd"
 style = "White"
 [[columns]]
@@ -612,6 +1179,9 @@ style = "White"
 kind = "SigPnd"
 style = "White"
 [[columns]]
+kind = "Slice"
+style = "White"
+[[columns]]
 kind = "Ssb"
 style = "White"
 [[columns]]
@@ -621,6 +1191,15 @@ style = "White"
 kind = "State"
 style = "White"
 [[columns]]
+kind = "SwapPss"
+style = "ByUnit"
+[[columns]]
+kind = "Syscall"
+style = "White"
+[[columns]]
+kind = "TcpConn"
+style = "White"
+[[columns]]
 kind = "TcpPort"
 style = "White"
 [[columns]]
@@ -648,6 +1227,12 @@ style = "White"
 kind = "UidSaved"
 style = "White"
 [[columns]]
+kind = "Unit"
+style = "White"
+[[columns]]
+kind = "UnixSocket"
+style = "White"
+[[columns]]
 kind = "UsageCpu"
 style = "White"
 [[columns]]
@@ -669,6 +1254,9 @@ style = "White"
 kind = "UserSaved"
 style = "White"
 [[columns]]
+kind = "Uss"
+style = "ByUnit"
+[[columns]]
 kind = "VmData"
 style = "ByUnit"
 [[columns]]
@@ -705,9 +1293,15 @@ style = "ByUnit"
 kind = "VmSwap"
 style = "ByUnit"
 [[columns]]
+kind = "VolCtxSw"
+style = "White"
+[[columns]]
 kind = "Wchan"
 style = "White"
 [[columns]]
 kind = "WriteBytes"
 style = "White"
+[[columns]]
+kind = "WriteHistory"
+style = "White"
 "#;
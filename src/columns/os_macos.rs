@@ -1,9 +1,11 @@
 pub mod command;
 pub mod context_sw;
 pub mod cpu_time;
+pub mod cwd;
 #[cfg(feature = "docker")]
 pub mod docker;
 pub mod empty;
+pub mod exe;
 pub mod gid;
 pub mod gid_real;
 pub mod gid_saved;
@@ -20,6 +22,7 @@ pub mod priority;
 pub mod read_bytes;
 pub mod separator;
 pub mod slot;
+pub mod sparkline;
 pub mod start_time;
 pub mod state;
 pub mod tcp_port;
@@ -42,9 +45,11 @@ pub mod write_bytes;
 pub use self::command::Command;
 pub use self::context_sw::ContextSw;
 pub use self::cpu_time::CpuTime;
+pub use self::cwd::Cwd;
 #[cfg(feature = "docker")]
 pub use self::docker::Docker;
 pub use self::empty::Empty;
+pub use self::exe::Exe;
 pub use self::gid::Gid;
 pub use self::gid_real::GidReal;
 pub use self::gid_saved::GidSaved;
@@ -61,6 +66,7 @@ pub use self::priority::Priority;
 pub use self::read_bytes::ReadBytes;
 pub use self::separator::Separator;
 pub use self::slot::Slot;
+pub use self::sparkline::Sparkline;
 pub use self::start_time::StartTime;
 pub use self::state::State;
 pub use self::tcp_port::TcpPort;
@@ -93,9 +99,12 @@ use std::collections::HashMap;
 pub enum ConfigColumnKind {
     Command,
     ContextSw,
+    CpuHistory,
     CpuTime,
+    Cwd,
     Docker,
     Empty,
+    Exe,
     Gid,
     GidReal,
     GidSaved,
@@ -103,6 +112,7 @@ pub enum ConfigColumnKind {
     GroupReal,
     GroupSaved,
     MajFlt,
+    MemHistory,
     MinFlt,
     Nice,
     Pid,
@@ -110,6 +120,8 @@ pub enum ConfigColumnKind {
     Ppid,
     Priority,
     ReadBytes,
+    ReadHistory,
+    RssHistory,
     Separator,
     Slot,
     StartTime,
@@ -131,12 +143,14 @@ pub enum ConfigColumnKind {
     VmRss,
     VmSize,
     WriteBytes,
+    WriteHistory,
 }
 
 // ---------------------------------------------------------------------------------------------------------------------
 // gen_column
 // ---------------------------------------------------------------------------------------------------------------------
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_column(
     kind: &ConfigColumnKind,
     header: Option<String>,
@@ -144,16 +158,31 @@ pub fn gen_column(
     separator: &str,
     abbr_sid: bool,
     tree_symbols: &[String; 5],
+    _env: &[String],
+    _resolve_container_name: bool,
+    _mark_foreign_ns: bool,
+    _decode_signal: bool,
+    _rate: bool,
 ) -> Box<dyn Column> {
     match kind {
         ConfigColumnKind::Command => Box::new(Command::new(header)),
         ConfigColumnKind::ContextSw => Box::new(ContextSw::new(header)),
+        ConfigColumnKind::CpuHistory => Box::new(Sparkline::new(
+            header,
+            "CPU History",
+            "[%]",
+            Box::new(UsageCpu::new(None)),
+            100.0,
+            false,
+        )),
         ConfigColumnKind::CpuTime => Box::new(CpuTime::new(header)),
+        ConfigColumnKind::Cwd => Box::new(Cwd::new(header)),
         #[cfg(feature = "docker")]
         ConfigColumnKind::Docker => Box::new(Docker::new(header, _docker_path)),
         #[cfg(not(feature = "docker"))]
         ConfigColumnKind::Docker => Box::new(Empty::new()),
         ConfigColumnKind::Empty => Box::new(Empty::new()),
+        ConfigColumnKind::Exe => Box::new(Exe::new(header)),
         ConfigColumnKind::Gid => Box::new(Gid::new(header, abbr_sid)),
         ConfigColumnKind::GidReal => Box::new(GidReal::new(header)),
         ConfigColumnKind::GidSaved => Box::new(GidSaved::new(header)),
@@ -161,6 +190,14 @@ pub fn gen_column(
         ConfigColumnKind::GroupReal => Box::new(GroupReal::new(header)),
         ConfigColumnKind::GroupSaved => Box::new(GroupSaved::new(header)),
         ConfigColumnKind::MajFlt => Box::new(MajFlt::new(header)),
+        ConfigColumnKind::MemHistory => Box::new(Sparkline::new(
+            header,
+            "MEM History",
+            "[%]",
+            Box::new(UsageMem::new(None)),
+            100.0,
+            false,
+        )),
         ConfigColumnKind::MinFlt => Box::new(MinFlt::new(header)),
         ConfigColumnKind::Nice => Box::new(Nice::new(header)),
         ConfigColumnKind::Pid => Box::new(Pid::new(header)),
@@ -168,6 +205,22 @@ pub fn gen_column(
         ConfigColumnKind::Ppid => Box::new(Ppid::new(header)),
         ConfigColumnKind::Priority => Box::new(Priority::new(header)),
         ConfigColumnKind::ReadBytes => Box::new(ReadBytes::new(header)),
+        ConfigColumnKind::ReadHistory => Box::new(Sparkline::new(
+            header,
+            "Read History",
+            "[B/s]",
+            Box::new(ReadBytes::new(None)),
+            0.0,
+            true,
+        )),
+        ConfigColumnKind::RssHistory => Box::new(Sparkline::new(
+            header,
+            "RSS History",
+            "[bytes]",
+            Box::new(VmRss::new(None)),
+            0.0,
+            true,
+        )),
         ConfigColumnKind::Separator => Box::new(Separator::new(separator)),
         ConfigColumnKind::Slot => Box::new(Slot::new()),
         ConfigColumnKind::StartTime => Box::new(StartTime::new(header)),
@@ -189,6 +242,14 @@ pub fn gen_column(
         ConfigColumnKind::VmRss => Box::new(VmRss::new(header)),
         ConfigColumnKind::VmSize => Box::new(VmSize::new(header)),
         ConfigColumnKind::WriteBytes => Box::new(WriteBytes::new(header)),
+        ConfigColumnKind::WriteHistory => Box::new(Sparkline::new(
+            header,
+            "Write History",
+            "[B/s]",
+            Box::new(WriteBytes::new(None)),
+            0.0,
+            true,
+        )),
     }
 }
 
@@ -206,15 +267,21 @@ lazy_static! {
             ConfigColumnKind::ContextSw,
             ("ContextSw", "Context switch count")
         ),
+        (
+            ConfigColumnKind::CpuHistory,
+            ("CpuHistory", "CPU utilization history")
+        ),
         (
             ConfigColumnKind::CpuTime,
             ("CpuTime", "Cumulative CPU time")
         ),
+        (ConfigColumnKind::Cwd, ("Cwd", "Current working directory")),
         (
             ConfigColumnKind::Docker,
             ("Docker", "Docker container name")
         ),
         (ConfigColumnKind::Empty, ("Empty", "Empty")),
+        (ConfigColumnKind::Exe, ("Exe", "Executable path")),
         (ConfigColumnKind::Gid, ("Gid", "Group ID")),
         (ConfigColumnKind::GidReal, ("GidReal", "Real group ID")),
         (ConfigColumnKind::GidSaved, ("GidSaved", "Saved group ID")),
@@ -231,6 +298,10 @@ lazy_static! {
             ConfigColumnKind::MajFlt,
             ("MajFlt", "Major page fault count")
         ),
+        (
+            ConfigColumnKind::MemHistory,
+            ("MemHistory", "Memory utilization history")
+        ),
         (
             ConfigColumnKind::MinFlt,
             ("MinFlt", "Minor page fault count")
@@ -244,6 +315,14 @@ lazy_static! {
             ConfigColumnKind::ReadBytes,
             ("ReadBytes", "Read bytes from storage")
         ),
+        (
+            ConfigColumnKind::ReadHistory,
+            ("ReadHistory", "Read bytes history")
+        ),
+        (
+            ConfigColumnKind::RssHistory,
+            ("RssHistory", "Resident set size history")
+        ),
         (
             ConfigColumnKind::Separator,
             ("Separator", "Show | for column separation")
@@ -278,6 +357,10 @@ lazy_static! {
             ConfigColumnKind::WriteBytes,
             ("WriteBytes", "Write bytes to storage")
         ),
+        (
+            ConfigColumnKind::WriteHistory,
+            ("WriteHistory", "Write bytes history")
+        ),
     ]
     .iter()
     .cloned()
@@ -342,7 +425,6 @@ nonnumeric_search = true
 // CONFIG_ALL
 // ---------------------------------------------------------------------------------------------------------------------
 
-#[cfg(test)]
 pub static CONFIG_ALL: &str = r#"
 [[columns]]
 kind = "Command"
@@ -353,16 +435,25 @@ kind = "ContextSw"
 style = "BrightRed"
 align = "Right"
 [[columns]]
+kind = "CpuHistory"
+style = "White"
+[[columns]]
 kind = "CpuTime"
 style = "BrightGreen"
 align = "Center"
 [[columns]]
+kind = "Cwd"
+style = "White"
+[[columns]]
 kind = "Docker"
 style = "BrightMagenta"
 [[columns]]
 kind = "Empty"
 style = "BrightYellow"
 [[columns]]
+kind = "Exe"
+style = "White"
+[[columns]]
 kind = "Gid"
 style = "White"
 [[columns]]
@@ -384,6 +475,9 @@ style = "White"
 kind = "MajFlt"
 style = "BrightCyan"
 [[columns]]
+kind = "MemHistory"
+style = "White"
+[[columns]]
 kind = "MinFlt"
 style = "BrightWhite"
 [[columns]]
@@ -405,6 +499,12 @@ style = "Blue"
 kind = "ReadBytes"
 style = "Cyan"
 [[columns]]
+kind = "ReadHistory"
+style = "White"
+[[columns]]
+kind = "RssHistory"
+style = "White"
+[[columns]]
 kind = "Separator"
 style = "White"
 [[columns]]
@@ -458,4 +558,7 @@ style = "ByUnit"
 [[columns]]
 kind = "WriteBytes"
 style = "White"
+[[columns]]
+kind = "WriteHistory"
+style = "White"
 "#;
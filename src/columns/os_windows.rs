@@ -10,6 +10,7 @@ pub mod priority;
 pub mod read_bytes;
 pub mod separator;
 pub mod slot;
+pub mod sparkline;
 pub mod start_time;
 pub mod threads;
 pub mod tree;
@@ -37,6 +38,7 @@ pub use self::priority::Priority;
 pub use self::read_bytes::ReadBytes;
 pub use self::separator::Separator;
 pub use self::slot::Slot;
+pub use self::sparkline::Sparkline;
 pub use self::start_time::StartTime;
 pub use self::threads::Threads;
 pub use self::tree::Tree;
@@ -64,15 +66,19 @@ use std::collections::HashMap;
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ConfigColumnKind {
     Command,
+    CpuHistory,
     CpuTime,
     Empty,
     Gid,
     Group,
     MajFlt,
+    MemHistory,
     Pid,
     Ppid,
     Priority,
     ReadBytes,
+    ReadHistory,
+    RssHistory,
     Separator,
     Slot,
     StartTime,
@@ -89,12 +95,14 @@ pub enum ConfigColumnKind {
     VmSize,
     VmSwap,
     WriteBytes,
+    WriteHistory,
 }
 
 // ---------------------------------------------------------------------------------------------------------------------
 // gen_column
 // ---------------------------------------------------------------------------------------------------------------------
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_column(
     kind: &ConfigColumnKind,
     header: Option<String>,
@@ -102,18 +110,55 @@ pub fn gen_column(
     separator: &str,
     abbr_sid: bool,
     tree_symbols: &[String; 5],
+    _env: &[String],
+    _resolve_container_name: bool,
+    _mark_foreign_ns: bool,
+    _decode_signal: bool,
+    _rate: bool,
 ) -> Box<dyn Column> {
     match kind {
         ConfigColumnKind::Command => Box::new(Command::new(header)),
+        ConfigColumnKind::CpuHistory => Box::new(Sparkline::new(
+            header,
+            "CPU History",
+            "[%]",
+            Box::new(UsageCpu::new(None)),
+            100.0,
+            false,
+        )),
         ConfigColumnKind::CpuTime => Box::new(CpuTime::new(header)),
         ConfigColumnKind::Empty => Box::new(Empty::new()),
         ConfigColumnKind::Gid => Box::new(Gid::new(header, abbr_sid)),
         ConfigColumnKind::Group => Box::new(Group::new(header, abbr_sid)),
         ConfigColumnKind::MajFlt => Box::new(MajFlt::new(header)),
+        ConfigColumnKind::MemHistory => Box::new(Sparkline::new(
+            header,
+            "MEM History",
+            "[%]",
+            Box::new(UsageMem::new(None)),
+            100.0,
+            false,
+        )),
         ConfigColumnKind::Pid => Box::new(Pid::new(header)),
         ConfigColumnKind::Ppid => Box::new(Ppid::new(header)),
         ConfigColumnKind::Priority => Box::new(Priority::new(header)),
         ConfigColumnKind::ReadBytes => Box::new(ReadBytes::new(header)),
+        ConfigColumnKind::ReadHistory => Box::new(Sparkline::new(
+            header,
+            "Read History",
+            "[B/s]",
+            Box::new(ReadBytes::new(None)),
+            0.0,
+            true,
+        )),
+        ConfigColumnKind::RssHistory => Box::new(Sparkline::new(
+            header,
+            "RSS History",
+            "[bytes]",
+            Box::new(VmRss::new(None)),
+            0.0,
+            true,
+        )),
         ConfigColumnKind::Separator => Box::new(Separator::new(separator)),
         ConfigColumnKind::Slot => Box::new(Slot::new()),
         ConfigColumnKind::StartTime => Box::new(StartTime::new(header)),
@@ -130,6 +175,14 @@ pub fn gen_column(
         ConfigColumnKind::VmSize => Box::new(VmSize::new(header)),
         ConfigColumnKind::VmSwap => Box::new(VmSwap::new(header)),
         ConfigColumnKind::WriteBytes => Box::new(WriteBytes::new(header)),
+        ConfigColumnKind::WriteHistory => Box::new(Sparkline::new(
+            header,
+            "Write History",
+            "[B/s]",
+            Box::new(WriteBytes::new(None)),
+            0.0,
+            true,
+        )),
     }
 }
 
@@ -143,6 +196,10 @@ lazy_static! {
             ConfigColumnKind::Command,
             ("Command", "Command with all arguments")
         ),
+        (
+            ConfigColumnKind::CpuHistory,
+            ("CpuHistory", "CPU utilization history")
+        ),
         (
             ConfigColumnKind::CpuTime,
             ("CpuTime", "Cumulative CPU time")
@@ -154,6 +211,10 @@ lazy_static! {
             ConfigColumnKind::MajFlt,
             ("MajFlt", "Major page fault count")
         ),
+        (
+            ConfigColumnKind::MemHistory,
+            ("MemHistory", "Memory utilization history")
+        ),
         (ConfigColumnKind::Pid, ("Pid", "Process ID")),
         (ConfigColumnKind::Ppid, ("Ppid", "Parent process ID")),
         (ConfigColumnKind::Priority, ("Priority", "Priority")),
@@ -161,6 +222,14 @@ lazy_static! {
             ConfigColumnKind::ReadBytes,
             ("ReadBytes", "Read bytes from storage")
         ),
+        (
+            ConfigColumnKind::ReadHistory,
+            ("ReadHistory", "Read bytes history")
+        ),
+        (
+            ConfigColumnKind::RssHistory,
+            ("RssHistory", "Resident set size history")
+        ),
         (
             ConfigColumnKind::Separator,
             ("Separator", "Show | for column separation")
@@ -194,6 +263,10 @@ lazy_static! {
             ConfigColumnKind::WriteBytes,
             ("WriteBytes", "Write bytes to storage")
         ),
+        (
+            ConfigColumnKind::WriteHistory,
+            ("WriteHistory", "Write bytes history")
+        ),
     ]
     .iter()
     .cloned()
@@ -253,13 +326,15 @@ nonnumeric_search = true
 // CONFIG_ALL
 // ---------------------------------------------------------------------------------------------------------------------
 
-#[cfg(test)]
 pub static CONFIG_ALL: &str = r#"
 [[columns]]
 kind = "Command"
 style = "BrightRed"
 align = "Left"
 [[columns]]
+kind = "CpuHistory"
+style = "White"
+[[columns]]
 kind = "CpuTime"
 style = "BrightGreen"
 align = "Center"
@@ -276,6 +351,9 @@ style = "White"
 kind = "MajFlt"
 style = "BrightCyan"
 [[columns]]
+kind = "MemHistory"
+style = "White"
+[[columns]]
 kind = "Pid"
 style = "Green"
 [[columns]]
@@ -288,6 +366,12 @@ style = "Blue"
 kind = "ReadBytes"
 style = "Cyan"
 [[columns]]
+kind = "ReadHistory"
+style = "White"
+[[columns]]
+kind = "RssHistory"
+style = "White"
+[[columns]]
 kind = "Separator"
 style = "White"
 [[columns]]
@@ -329,4 +413,7 @@ style = "ByUnit"
 [[columns]]
 kind = "WriteBytes"
 style = "White"
+[[columns]]
+kind = "WriteHistory"
+style = "White"
 "#;
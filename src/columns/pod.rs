@@ -0,0 +1,202 @@
+use crate::process::ProcessInfo;
+use crate::Column;
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Pod {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    uids: HashMap<i32, String>,
+    #[cfg(target_os = "linux")]
+    pods: HashMap<String, (String, String)>,
+}
+
+impl Pod {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Pod"));
+        let unit = String::from("");
+        Pod {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            uids: HashMap::new(),
+            #[cfg(target_os = "linux")]
+            pods: read_pods(),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for Pod {
+    fn refresh(&mut self) {
+        self.pods = read_pods();
+    }
+
+    fn add(&mut self, proc: &ProcessInfo) {
+        let path = proc
+            .curr_proc
+            .cgroups()
+            .ok()
+            .and_then(|x| crate::cgroup::unified_path(&x));
+        let info = path.as_ref().and_then(|x| crate::cgroup::parse_pod(x));
+
+        let fmt_content = if let (Some(path), Some(info)) = (path, info) {
+            let name = if let Some((_, name)) = resolve_pod(&mut self.pods, &path, &info.uid) {
+                name.clone()
+            } else {
+                info.uid.clone()
+            };
+            self.uids.insert(proc.pid, info.uid);
+            format!("{} ({})", name, info.qos)
+        } else {
+            String::from("")
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    // Pod UID can be searched even if the name is resolved
+    fn find_partial(&self, pid: i32, keyword: &str) -> bool {
+        let uid = self.uids.get(&pid).map(|x| x.contains(keyword));
+        let content = self.fmt_contents.get(&pid).map(|x| x.contains(keyword));
+        uid.unwrap_or(false) || content.unwrap_or(false)
+    }
+
+    fn find_exact(&self, pid: i32, keyword: &str) -> bool {
+        let uid = self.uids.get(&pid).map(|x| x == keyword);
+        let content = self
+            .fmt_contents
+            .get(&pid)
+            .map(|x| x.split(' ').next() == Some(keyword));
+        uid.unwrap_or(false) || content.unwrap_or(false)
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_sorted_pid!(String);
+    crate::column_default_apply_visible!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
+
+// Pod names and namespaces are read from the kubelet's local state without API server.
+// The returned map is pod UID -> (namespace, name).
+// Pods without log directories are resolved by their containers in resolve_pod.
+#[cfg(target_os = "linux")]
+pub fn read_pods() -> HashMap<String, (String, String)> {
+    use std::fs;
+
+    let mut ret = HashMap::new();
+
+    // Log directories are named as <namespace>_<name>_<uid>
+    if let Ok(dir) = fs::read_dir("/var/log/pods") {
+        for entry in dir.flatten() {
+            let dir_name = entry.file_name().to_string_lossy().to_string();
+            let fields: Vec<&str> = dir_name.split('_').collect();
+            if fields.len() == 3 {
+                ret.insert(
+                    String::from(fields[2]),
+                    (String::from(fields[0]), String::from(fields[1])),
+                );
+            }
+        }
+    }
+
+    ret
+}
+
+// kubelet sets the pod metadata to labels or annotations of each container.
+// The pod of the container which the cgroup path points is cached to the map.
+#[cfg(target_os = "linux")]
+pub fn resolve_pod<'a>(
+    pods: &'a mut HashMap<String, (String, String)>,
+    path: &str,
+    uid: &str,
+) -> Option<&'a (String, String)> {
+    if !pods.contains_key(uid) {
+        let pod =
+            crate::cgroup::parse_container(path).and_then(|x| read_pod_labels(x.runtime, &x.id));
+        if let Some(pod) = pod {
+            pods.insert(String::from(uid), pod);
+        }
+    }
+    pods.get(uid)
+}
+
+#[cfg(target_os = "linux")]
+fn read_pod_labels(runtime: &str, id: &str) -> Option<(String, String)> {
+    let (path, pointer) = match runtime {
+        "docker" => (
+            format!("/var/lib/docker/containers/{}/config.v2.json", id),
+            "/Config/Labels",
+        ),
+        "crio" => (
+            format!(
+                "/var/lib/containers/storage/overlay-containers/{}/userdata/config.json",
+                id
+            ),
+            "/annotations",
+        ),
+        "containerd" | "cri" => (
+            format!(
+                "/run/containerd/io.containerd.runtime.v2.task/k8s.io/{}/config.json",
+                id
+            ),
+            "/annotations",
+        ),
+        _ => return None,
+    };
+    let s = std::fs::read_to_string(path).ok()?;
+    parse_pod_labels(&s, pointer)
+}
+
+// Labels are io.kubernetes.pod.* by dockershim and CRI-O, and io.kubernetes.cri.sandbox-* by containerd
+#[cfg(target_os = "linux")]
+fn parse_pod_labels(s: &str, pointer: &str) -> Option<(String, String)> {
+    let config: serde_json::Value = serde_json::from_str(s).ok()?;
+    let labels = config.pointer(pointer)?;
+    let keys = [
+        ("io.kubernetes.pod.namespace", "io.kubernetes.pod.name"),
+        (
+            "io.kubernetes.cri.sandbox-namespace",
+            "io.kubernetes.cri.sandbox-name",
+        ),
+    ];
+    keys.iter().find_map(|(namespace, name)| {
+        let namespace = labels.get(namespace)?.as_str()?;
+        let name = labels.get(name)?.as_str()?;
+        Some((String::from(namespace), String::from(name)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pod_labels() {
+        let docker = r#"{"Config":{"Labels":{"io.kubernetes.pod.name":"web-7d4b9c","io.kubernetes.pod.namespace":"shop","io.kubernetes.pod.uid":"1234"}}}"#;
+        assert_eq!(
+            parse_pod_labels(docker, "/Config/Labels"),
+            Some((String::from("shop"), String::from("web-7d4b9c")))
+        );
+        let containerd = r#"{"ociVersion":"1.0.2","annotations":{"io.kubernetes.cri.container-type":"container","io.kubernetes.cri.sandbox-name":"db-0","io.kubernetes.cri.sandbox-namespace":"data"}}"#;
+        assert_eq!(
+            parse_pod_labels(containerd, "/annotations"),
+            Some((String::from("data"), String::from("db-0")))
+        );
+        assert_eq!(
+            parse_pod_labels(r#"{"annotations":{}}"#, "/annotations"),
+            None
+        );
+    }
+}
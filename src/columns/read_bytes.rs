@@ -45,6 +45,7 @@ impl Column for ReadBytes {
     }
 
     column_default!(u64);
+    crate::column_default_history_value!();
 }
 
 #[cfg_attr(tarpaulin, skip)]
@@ -67,6 +68,7 @@ impl Column for ReadBytes {
     }
 
     column_default!(u64);
+    crate::column_default_history_value!();
 }
 
 #[cfg_attr(tarpaulin, skip)]
@@ -84,4 +86,5 @@ impl Column for ReadBytes {
     }
 
     column_default!(u64);
+    crate::column_default_history_value!();
 }
@@ -0,0 +1,73 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column};
+use procfs::process::Schedstat;
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct SchedRate {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, u32>,
+    width: usize,
+    value: fn(&Schedstat) -> u64,
+    percent: bool,
+}
+
+impl SchedRate {
+    pub fn new(
+        header: Option<String>,
+        default_header: &str,
+        value: fn(&Schedstat) -> u64,
+        percent: bool,
+    ) -> Self {
+        let header = header.unwrap_or_else(|| String::from(default_header));
+        let unit = if percent {
+            String::from("[%]")
+        } else {
+            String::from("[/s]")
+        };
+        SchedRate {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            value,
+            percent,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for SchedRate {
+    fn require_schedstat(&self) -> bool {
+        true
+    }
+
+    fn add(&mut self, proc: &ProcessInfo) {
+        let (fmt_content, raw_content) =
+            if let (Some(curr), Some(prev)) = (&proc.curr_schedstat, &proc.prev_schedstat) {
+                let delta = (self.value)(curr).saturating_sub((self.value)(prev));
+                let interval_ns = proc.interval.as_nanos() as f64;
+                // Run and wait times are nanoseconds, and timeslices are counts
+                let rate = if self.percent {
+                    delta as f64 * 100.0 / interval_ns
+                } else {
+                    delta as f64 * 1_000_000_000.0 / interval_ns
+                };
+                (format!("{:.1}", rate), (rate * 1000.0) as u32)
+            } else {
+                (String::from(""), 0)
+            };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn history_value(&self, pid: i32) -> Option<f64> {
+        self.raw_contents.get(&pid).map(|x| *x as f64 / 1000.0)
+    }
+
+    column_default!(u32);
+}
@@ -0,0 +1,46 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Seccomp {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, u32>,
+    width: usize,
+}
+
+impl Seccomp {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Seccomp"));
+        let unit = String::from("");
+        Seccomp {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for Seccomp {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let val = proc.curr_status.as_ref().and_then(|x| x.seccomp);
+        let fmt_content = match val {
+            Some(0) => String::from("disabled"),
+            Some(1) => String::from("strict"),
+            Some(2) => String::from("filter"),
+            Some(x) => format!("{}", x),
+            None => String::from(""),
+        };
+        let raw_content = val.unwrap_or(0);
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u32);
+}
@@ -1,5 +1,6 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::util::signal_names;
+use crate::Column;
 use std::cmp;
 use std::collections::HashMap;
 
@@ -9,10 +10,11 @@ pub struct SigBlk {
     fmt_contents: HashMap<i32, String>,
     raw_contents: HashMap<i32, u64>,
     width: usize,
+    decode: bool,
 }
 
 impl SigBlk {
-    pub fn new(header: Option<String>) -> Self {
+    pub fn new(header: Option<String>, decode: bool) -> Self {
         let header = header.unwrap_or_else(|| String::from("SigBlk"));
         let unit = String::from("");
         SigBlk {
@@ -21,6 +23,7 @@ impl SigBlk {
             width: 0,
             header,
             unit,
+            decode,
         }
     }
 }
@@ -29,7 +32,11 @@ impl Column for SigBlk {
     fn add(&mut self, proc: &ProcessInfo) {
         let (fmt_content, raw_content) = if let Some(ref status) = proc.curr_status {
             let val = status.sigblk;
-            (format!("{:016x}", val), val)
+            if self.decode {
+                (signal_names(val), val)
+            } else {
+                (format!("{:016x}", val), val)
+            }
         } else {
             (String::from(""), 0)
         };
@@ -38,5 +45,21 @@ impl Column for SigBlk {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
-    column_default!(u64);
+    fn find_exact(&self, pid: i32, keyword: &str) -> bool {
+        if let Some(content) = self.fmt_contents.get(&pid) {
+            content.split(',').any(|x| x.eq_ignore_ascii_case(keyword))
+        } else {
+            false
+        }
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_find_partial!();
+    crate::column_default_sorted_pid!(u64);
+    crate::column_default_apply_visible!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
 }
@@ -0,0 +1,101 @@
+use crate::process::ProcessInfo;
+use crate::util::bytify;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+// The sum of the fields in smaps_rollup, or smaps on kernels older than 4.14
+pub struct Smaps {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    keys: &'static [&'static str],
+}
+
+impl Smaps {
+    pub fn new(
+        header: Option<String>,
+        default_header: &str,
+        keys: &'static [&'static str],
+    ) -> Self {
+        let header = header.unwrap_or_else(|| String::from(default_header));
+        let unit = String::from("[bytes]");
+        Smaps {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            keys,
+        }
+    }
+}
+
+// The fields of all mappings are summed up, so smaps and smaps_rollup can be parsed in the same way
+fn sum_fields(s: &str, keys: &[&str]) -> u64 {
+    let mut ret = 0;
+    for line in s.lines() {
+        if let Some(pos) = line.find(':') {
+            if keys.contains(&&line[..pos]) {
+                let kb = line[pos + 1..]
+                    .trim()
+                    .trim_end_matches("kB")
+                    .trim()
+                    .parse::<u64>()
+                    .unwrap_or(0);
+                ret += kb * 1024;
+            }
+        }
+    }
+    ret
+}
+
+#[cfg(target_os = "linux")]
+impl Column for Smaps {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let path = format!("/proc/{}", proc.pid);
+        let smaps = std::fs::read_to_string(format!("{}/smaps_rollup", path))
+            .or_else(|_| std::fs::read_to_string(format!("{}/smaps", path)));
+        let (fmt_content, raw_content) = if let Ok(smaps) = smaps {
+            let raw_content = sum_fields(&smaps, self.keys);
+            (bytify(raw_content), raw_content)
+        } else {
+            (String::from(""), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u64);
+    crate::column_default_history_value!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sum_fields() {
+        let smaps = "00400000-0040b000 r-xp 00000000 08:01 123 /bin/cat
+Rss:                  44 kB
+Pss:                  22 kB
+Private_Clean:         4 kB
+Private_Dirty:         8 kB
+VmFlags: rd ex mr mw me dw
+7ffd1000-7ffd2000 rw-p 00000000 00:00 0 [stack]
+Rss:                  12 kB
+Pss:                  12 kB
+Private_Clean:         0 kB
+Private_Dirty:        12 kB
+";
+        assert_eq!(sum_fields(smaps, &["Pss"]), 34 * 1024);
+        assert_eq!(
+            sum_fields(smaps, &["Private_Clean", "Private_Dirty"]),
+            24 * 1024
+        );
+        assert_eq!(sum_fields(smaps, &["SwapPss"]), 0);
+    }
+}
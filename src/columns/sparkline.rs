@@ -0,0 +1,132 @@
+use crate::columns::ConfigColumnKind;
+use crate::history::History;
+use crate::process::ProcessInfo;
+use crate::util::bytify;
+use crate::{column_default, Column};
+use std::cmp;
+use std::collections::HashMap;
+
+const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+pub struct Sparkline {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    source: Box<dyn Column>,
+    scale: f64,
+    bytes: bool,
+}
+
+impl Sparkline {
+    pub fn new(
+        header: Option<String>,
+        default_header: &str,
+        unit: &str,
+        source: Box<dyn Column>,
+        scale: f64,
+        bytes: bool,
+    ) -> Self {
+        let header = header.unwrap_or_else(|| String::from(default_header));
+        let unit = String::from(unit);
+        Sparkline {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            source,
+            scale,
+            bytes,
+        }
+    }
+
+    fn update(&mut self, pid: i32, values: &[f64]) {
+        let (fmt_content, raw_content) = if values.is_empty() {
+            (String::from(""), 0)
+        } else {
+            let min = values.iter().cloned().fold(f64::MAX, f64::min);
+            let max = values.iter().cloned().fold(f64::MIN, f64::max);
+            let avg = values.iter().sum::<f64>() / values.len() as f64;
+            let stat = if self.bytes {
+                format!(
+                    "{}/{}/{}",
+                    bytify(min as u64),
+                    bytify(avg as u64),
+                    bytify(max as u64)
+                )
+            } else {
+                format!("{:.1}/{:.1}/{:.1}", min, avg, max)
+            };
+            (
+                format!("{} {}", sparkline(values, self.scale), stat),
+                (avg * 1000.0) as u64,
+            )
+        };
+
+        self.fmt_contents.insert(pid, fmt_content);
+        self.raw_contents.insert(pid, raw_content);
+    }
+}
+
+// The top of the bars is `scale` at least, so that small fluctuations are not drawn as full bars
+pub fn sparkline(values: &[f64], scale: f64) -> String {
+    let top = values.iter().cloned().fold(scale, f64::max);
+    values
+        .iter()
+        .map(|x| {
+            let level = if top > 0.0 {
+                (x / top * (BLOCKS.len() - 1) as f64).round() as usize
+            } else {
+                0
+            };
+            BLOCKS[cmp::min(level, BLOCKS.len() - 1)]
+        })
+        .collect()
+}
+
+impl Column for Sparkline {
+    fn add(&mut self, proc: &ProcessInfo) {
+        self.source.add(proc);
+        let values: Vec<f64> = self.source.history_value(proc.pid).into_iter().collect();
+        self.update(proc.pid, &values);
+    }
+
+    fn available(&self) -> bool {
+        self.source.available()
+    }
+
+    fn require_history(&self) -> bool {
+        true
+    }
+
+    fn history_value(&self, pid: i32) -> Option<f64> {
+        self.source.history_value(pid)
+    }
+
+    fn apply_history(&mut self, kind: &ConfigColumnKind, history: &History) {
+        let pids: Vec<i32> = self.fmt_contents.keys().copied().collect();
+        for pid in pids {
+            if let Some(values) = history.get(kind, pid) {
+                let values: Vec<f64> = values.iter().copied().collect();
+                self.update(pid, &values);
+            }
+        }
+    }
+
+    column_default!(u64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[0.0, 50.0, 100.0], 100.0), String::from("▁▅█"));
+        assert_eq!(sparkline(&[0.0, 1.0, 2.0], 100.0), String::from("▁▁▁"));
+        assert_eq!(sparkline(&[0.0, 200.0, 400.0], 100.0), String::from("▁▅█"));
+        assert_eq!(sparkline(&[0.0, 0.0], 0.0), String::from("▁▁"));
+    }
+}
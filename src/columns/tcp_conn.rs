@@ -0,0 +1,115 @@
+use crate::process::ProcessInfo;
+use crate::Column;
+use procfs::net::TcpState;
+use std::cmp;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+
+pub struct TcpConn {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    remotes: HashMap<i32, Vec<SocketAddr>>,
+}
+
+impl TcpConn {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("TCP Conn"));
+        let unit = String::from("");
+        TcpConn {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            remotes: HashMap::new(),
+        }
+    }
+}
+
+// Dual-stack sockets show IPv4 peers as IPv4-mapped IPv6 addresses like [::ffff:10.0.3.7]
+fn unmap(addr: SocketAddr) -> SocketAddr {
+    match addr {
+        SocketAddr::V6(x) => match x.ip().to_ipv4_mapped() {
+            Some(ip) => SocketAddr::new(IpAddr::V4(ip), x.port()),
+            None => addr,
+        },
+        SocketAddr::V4(_) => addr,
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for TcpConn {
+    fn require_socket(&self) -> bool {
+        true
+    }
+
+    fn add(&mut self, proc: &ProcessInfo) {
+        let mut conns = Vec::new();
+        for sock in proc.curr_socks.iter().flatten() {
+            if let Some(entry) = proc.socket_index.tcp.get(sock) {
+                if entry.state == TcpState::Established {
+                    conns.push((unmap(entry.local_address), unmap(entry.remote_address)));
+                }
+            }
+        }
+        conns.sort();
+        conns.dedup();
+
+        // The count is shown first because the list may be truncated
+        let fmt_content = if conns.is_empty() {
+            String::from("")
+        } else {
+            let list: Vec<String> = conns
+                .iter()
+                .map(|(local, remote)| format!("{} -> {}", local, remote))
+                .collect();
+            format!("{} [{}]", conns.len(), list.join(", "))
+        };
+        let raw_content = conns.len() as u64;
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+        self.remotes
+            .insert(proc.pid, conns.into_iter().map(|(_, x)| x).collect());
+    }
+
+    fn find_exact(&self, pid: i32, keyword: &str) -> bool {
+        if let Some(remotes) = self.remotes.get(&pid) {
+            remotes.iter().any(|x| {
+                x.to_string() == keyword
+                    || x.ip().to_string() == keyword
+                    || x.port().to_string() == keyword
+            })
+        } else {
+            false
+        }
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_find_partial!();
+    crate::column_default_sorted_pid!(u64);
+    crate::column_default_apply_visible!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unmap() {
+        let mapped: SocketAddr = "[::ffff:10.0.3.7]:5432".parse().unwrap();
+        assert_eq!(unmap(mapped).to_string(), "10.0.3.7:5432");
+        let v6: SocketAddr = "[2001:db8::1]:443".parse().unwrap();
+        assert_eq!(unmap(v6).to_string(), "[2001:db8::1]:443");
+        let v4: SocketAddr = "127.0.0.1:80".parse().unwrap();
+        assert_eq!(unmap(v4).to_string(), "127.0.0.1:80");
+    }
+}
@@ -3,9 +3,7 @@ use crate::Column;
 #[cfg(target_os = "macos")]
 use libproc::libproc::net_info::TcpSIState;
 #[cfg(target_os = "linux")]
-use procfs::net::{TcpNetEntry, TcpState};
-#[cfg(target_os = "linux")]
-use procfs::process::FDTarget;
+use procfs::net::TcpState;
 use std::cmp;
 use std::collections::HashMap;
 
@@ -15,10 +13,6 @@ pub struct TcpPort {
     fmt_contents: HashMap<i32, String>,
     raw_contents: HashMap<i32, String>,
     width: usize,
-    #[cfg(target_os = "linux")]
-    tcp_entry: Vec<TcpNetEntry>,
-    #[cfg(target_os = "linux")]
-    tcp6_entry: Vec<TcpNetEntry>,
 }
 
 impl TcpPort {
@@ -31,30 +25,21 @@ impl TcpPort {
             width: 0,
             header,
             unit,
-            #[cfg(target_os = "linux")]
-            tcp_entry: procfs::net::tcp().unwrap_or_default(),
-            #[cfg(target_os = "linux")]
-            tcp6_entry: procfs::net::tcp6().unwrap_or_default(),
         }
     }
 }
 
 #[cfg(target_os = "linux")]
 impl Column for TcpPort {
-    fn add(&mut self, proc: &ProcessInfo) {
-        let fmt_content = if let Ok(fds) = proc.curr_proc.fd() {
-            let mut socks = Vec::new();
-            for fd in fds {
-                if let FDTarget::Socket(x) = fd.target {
-                    socks.push(x)
-                }
-            }
+    fn require_socket(&self) -> bool {
+        true
+    }
 
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = if let Some(ref socks) = proc.curr_socks {
             let mut ports = Vec::new();
-            for sock in &socks {
-                let mut tcp_iter = self.tcp_entry.iter().chain(self.tcp6_entry.iter());
-                let entry = tcp_iter.find(|&x| x.inode == *sock);
-                if let Some(entry) = entry {
+            for sock in socks {
+                if let Some(entry) = proc.socket_index.tcp.get(sock) {
                     if entry.state == TcpState::Listen {
                         ports.push(entry.local_address.port());
                     }
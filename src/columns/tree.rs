@@ -232,7 +232,15 @@ mod tests {
             prev_proc: Process::myself().unwrap(),
             curr_io: None,
             prev_io: None,
+            curr_schedstat: None,
+            prev_schedstat: None,
+            curr_blkio_delay: None,
+            prev_blkio_delay: None,
             curr_status: None,
+            prev_status: None,
+            curr_fds: None,
+            curr_socks: None,
+            socket_index: Default::default(),
             interval: Duration::new(0, 0),
         };
 
@@ -243,7 +251,15 @@ mod tests {
             prev_proc: Process::myself().unwrap(),
             curr_io: None,
             prev_io: None,
+            curr_schedstat: None,
+            prev_schedstat: None,
+            curr_blkio_delay: None,
+            prev_blkio_delay: None,
             curr_status: None,
+            prev_status: None,
+            curr_fds: None,
+            curr_socks: None,
+            socket_index: Default::default(),
             interval: Duration::new(0, 0),
         };
 
@@ -254,7 +270,15 @@ mod tests {
             prev_proc: Process::myself().unwrap(),
             curr_io: None,
             prev_io: None,
+            curr_schedstat: None,
+            prev_schedstat: None,
+            curr_blkio_delay: None,
+            prev_blkio_delay: None,
             curr_status: None,
+            prev_status: None,
+            curr_fds: None,
+            curr_socks: None,
+            socket_index: Default::default(),
             interval: Duration::new(0, 0),
         };
 
@@ -1,9 +1,5 @@
 use crate::process::ProcessInfo;
 use crate::Column;
-#[cfg(target_os = "linux")]
-use procfs::net::UdpNetEntry;
-#[cfg(target_os = "linux")]
-use procfs::process::FDTarget;
 use std::cmp;
 use std::collections::HashMap;
 
@@ -13,10 +9,6 @@ pub struct UdpPort {
     fmt_contents: HashMap<i32, String>,
     raw_contents: HashMap<i32, String>,
     width: usize,
-    #[cfg(target_os = "linux")]
-    udp_entry: Vec<UdpNetEntry>,
-    #[cfg(target_os = "linux")]
-    udp6_entry: Vec<UdpNetEntry>,
 }
 
 impl UdpPort {
@@ -29,30 +21,21 @@ impl UdpPort {
             width: 0,
             header,
             unit,
-            #[cfg(target_os = "linux")]
-            udp_entry: procfs::net::udp().unwrap_or_default(),
-            #[cfg(target_os = "linux")]
-            udp6_entry: procfs::net::udp6().unwrap_or_default(),
         }
     }
 }
 
 #[cfg(target_os = "linux")]
 impl Column for UdpPort {
-    fn add(&mut self, proc: &ProcessInfo) {
-        let fmt_content = if let Ok(fds) = proc.curr_proc.fd() {
-            let mut socks = Vec::new();
-            for fd in fds {
-                if let FDTarget::Socket(x) = fd.target {
-                    socks.push(x)
-                }
-            }
+    fn require_socket(&self) -> bool {
+        true
+    }
 
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = if let Some(ref socks) = proc.curr_socks {
             let mut ports = Vec::new();
-            for sock in &socks {
-                let mut udp_iter = self.udp_entry.iter().chain(self.udp6_entry.iter());
-                let entry = udp_iter.find(|&x| x.inode == *sock);
-                if let Some(entry) = entry {
+            for sock in socks {
+                if let Some(entry) = proc.socket_index.udp.get(sock) {
                     ports.push(entry.local_address.port());
                 }
             }
@@ -0,0 +1,73 @@
+use crate::process::ProcessInfo;
+use crate::Column;
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct UnixSocket {
+    header: String,
+    unit: String,
+    fmt_contents: HashMap<i32, String>,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    paths: HashMap<i32, Vec<String>>,
+}
+
+impl UnixSocket {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Unix Socket"));
+        let unit = String::from("");
+        UnixSocket {
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            paths: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Column for UnixSocket {
+    fn require_socket(&self) -> bool {
+        true
+    }
+
+    fn add(&mut self, proc: &ProcessInfo) {
+        // Abstract names are prefixed by '@' in /proc/net/unix
+        let mut paths = Vec::new();
+        for sock in proc.curr_socks.iter().flatten() {
+            let entry = proc.socket_index.unix.get(sock);
+            if let Some(path) = entry.and_then(|x| x.path.as_ref()) {
+                paths.push(path.to_string_lossy().to_string());
+            }
+        }
+        paths.sort();
+        paths.dedup();
+
+        let fmt_content = format!("[{}]", paths.join(", "));
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+        self.paths.insert(proc.pid, paths);
+    }
+
+    fn find_exact(&self, pid: i32, keyword: &str) -> bool {
+        if let Some(paths) = self.paths.get(&pid) {
+            paths.iter().any(|x| x == keyword)
+        } else {
+            false
+        }
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_find_partial!();
+    crate::column_default_sorted_pid!(String);
+    crate::column_default_apply_visible!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
@@ -42,6 +42,10 @@ impl Column for UsageCpu {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn history_value(&self, pid: i32) -> Option<f64> {
+        self.raw_contents.get(&pid).map(|x| *x as f64 / 1000.0)
+    }
+
     column_default!(u32);
 }
 
@@ -64,6 +68,10 @@ impl Column for UsageCpu {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn history_value(&self, pid: i32) -> Option<f64> {
+        self.raw_contents.get(&pid).map(|x| *x as f64 / 1000.0)
+    }
+
     column_default!(u32);
 }
 
@@ -85,5 +93,9 @@ impl Column for UsageCpu {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn history_value(&self, pid: i32) -> Option<f64> {
+        self.raw_contents.get(&pid).map(|x| *x as f64 / 1000.0)
+    }
+
     column_default!(u32);
 }
@@ -88,6 +88,10 @@ impl Column for UsageMem {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn history_value(&self, pid: i32) -> Option<f64> {
+        self.raw_contents.get(&pid).map(|x| *x as f64 / 1000.0)
+    }
+
     column_default!(u32);
 }
 
@@ -103,6 +107,10 @@ impl Column for UsageMem {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn history_value(&self, pid: i32) -> Option<f64> {
+        self.raw_contents.get(&pid).map(|x| *x as f64 / 1000.0)
+    }
+
     column_default!(u32);
 }
 
@@ -118,5 +126,9 @@ impl Column for UsageMem {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn history_value(&self, pid: i32) -> Option<f64> {
+        self.raw_contents.get(&pid).map(|x| *x as f64 / 1000.0)
+    }
+
     column_default!(u32);
 }
@@ -37,6 +37,7 @@ impl Column for VmRss {
     }
 
     column_default!(u64);
+    crate::column_default_history_value!();
 }
 
 #[cfg_attr(tarpaulin, skip)]
@@ -51,6 +52,7 @@ impl Column for VmRss {
     }
 
     column_default!(u64);
+    crate::column_default_history_value!();
 }
 
 #[cfg_attr(tarpaulin, skip)]
@@ -65,4 +67,5 @@ impl Column for VmRss {
     }
 
     column_default!(u64);
+    crate::column_default_history_value!();
 }
@@ -68,6 +68,14 @@ fn default_color_bright_white() -> ConfigColor {
     ConfigColor::BrightWhite
 }
 
+fn default_history_length() -> usize {
+    20
+}
+
+fn default_change_ratio() -> f64 {
+    0.25
+}
+
 // ---------------------------------------------------------------------------------------------------------------------
 // ColumnInfo
 // ---------------------------------------------------------------------------------------------------------------------
@@ -101,7 +109,11 @@ pub struct Config {
     #[serde(default)]
     pub docker: ConfigDocker,
     #[serde(default)]
+    pub container: ConfigContainer,
+    #[serde(default)]
     pub pager: ConfigPager,
+    #[serde(default)]
+    pub watch: ConfigWatch,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -145,6 +157,7 @@ pub enum ConfigColumnStyle {
     White,
     Color256(u8),
     ByPercentage,
+    ByScore,
     ByState,
     ByUnit,
 }
@@ -169,6 +182,9 @@ pub struct ConfigColumn {
     pub max_width: Option<usize>,
     pub min_width: Option<usize>,
     pub header: Option<String>,
+    pub env: Option<Vec<String>>,
+    #[serde(default = "default_false")]
+    pub rate: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -329,6 +345,10 @@ pub struct ConfigDisplay {
     pub tree_symbols: [String; 5],
     #[serde(default = "default_true")]
     pub abbr_sid: bool,
+    #[serde(default = "default_false")]
+    pub mark_foreign_ns: bool,
+    #[serde(default = "default_true")]
+    pub decode_signal: bool,
 }
 
 impl Default for ConfigDisplay {
@@ -350,6 +370,8 @@ impl Default for ConfigDisplay {
                 String::from("└"),
             ],
             abbr_sid: true,
+            mark_foreign_ns: false,
+            decode_signal: true,
         }
     }
 }
@@ -397,6 +419,18 @@ impl Default for ConfigDocker {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConfigContainer {
+    #[serde(default = "default_true")]
+    pub resolve_name: bool,
+}
+
+impl Default for ConfigContainer {
+    fn default() -> Self {
+        ConfigContainer { resolve_name: true }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ConfigPager {
     #[serde(default = "default_pager_mode_auto")]
@@ -422,3 +456,23 @@ pub enum ConfigPagerMode {
     Always,
     Disable,
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConfigWatch {
+    #[serde(default = "default_history_length")]
+    pub history_length: usize,
+    #[serde(default = "default_true")]
+    pub highlight: bool,
+    #[serde(default = "default_change_ratio")]
+    pub change_ratio: f64,
+}
+
+impl Default for ConfigWatch {
+    fn default() -> Self {
+        ConfigWatch {
+            history_length: 20,
+            highlight: true,
+            change_ratio: 0.25,
+        }
+    }
+}
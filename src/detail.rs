@@ -0,0 +1,223 @@
+use crate::column::Column;
+use crate::columns::{gen_column, ConfigColumnKind, KIND_LIST};
+use crate::config::{Config, ConfigColumnAlign};
+use crate::history::History;
+use crate::process::ProcessInfo;
+use crate::term_info::TermInfo;
+use crate::util::{adjust, truncate};
+use anyhow::Error;
+use std::cmp;
+use unicode_width::UnicodeWidthStr;
+
+pub struct Detail {
+    pub pid: i32,
+    // Columns are built once because some of them are expensive to build (e.g. Docker)
+    columns: Vec<(ConfigColumnKind, &'static str, Box<dyn Column>)>,
+    lines: Vec<String>,
+    offset: usize,
+}
+
+impl Detail {
+    pub fn new(pid: i32, config: &Config) -> Self {
+        let mut kinds: Vec<(&ConfigColumnKind, &'static str)> =
+            KIND_LIST.iter().map(|(k, (v, _))| (k, *v)).collect();
+        kinds.sort_by_key(|(_, v)| *v);
+
+        let mut columns = Vec::new();
+        for (kind, name) in kinds {
+            match kind {
+                ConfigColumnKind::Empty
+                | ConfigColumnKind::Separator
+                | ConfigColumnKind::Slot
+                | ConfigColumnKind::Tree => continue,
+                _ => (),
+            }
+            let config_column = config.columns.iter().find(|x| &x.kind == kind);
+            let env = config_column
+                .and_then(|x| x.env.as_deref())
+                .unwrap_or_default();
+            let rate = config_column.map(|x| x.rate).unwrap_or(false);
+            let column = gen_column(
+                kind,
+                None,
+                &config.docker.path,
+                &config.display.separator,
+                config.display.abbr_sid,
+                &config.display.tree_symbols,
+                env,
+                config.container.resolve_name,
+                config.display.mark_foreign_ns,
+                config.display.decode_signal,
+                rate,
+            );
+            if column.available() {
+                columns.push((kind.clone(), name, column));
+            }
+        }
+
+        Detail {
+            pid,
+            columns,
+            lines: Vec::new(),
+            offset: 0,
+        }
+    }
+
+    pub fn update(&mut self, proc: &ProcessInfo, config: &Config, history: &History) {
+        let width = self
+            .columns
+            .iter()
+            .map(|(_, v, _)| UnicodeWidthStr::width(*v))
+            .max()
+            .unwrap_or(0);
+
+        let mut lines = vec![String::from("Columns")];
+        for (kind, name, column) in self.columns.iter_mut() {
+            column.refresh();
+            column.add(proc);
+            column.apply_history(kind, history);
+            column.reset_width(None, config, None, None);
+            column.update_width(proc.pid, None);
+            let content = column
+                .display_content(proc.pid, &ConfigColumnAlign::Left)
+                .unwrap_or_default();
+            lines.push(format!(
+                "  {} {}",
+                adjust(name, width, &ConfigColumnAlign::Left),
+                content.trim_end()
+            ));
+        }
+
+        for (title, contents) in extra_info(proc) {
+            lines.push(String::from(""));
+            lines.push(String::from(title));
+            for x in contents {
+                lines.push(format!("  {}", x));
+            }
+        }
+
+        self.lines = lines;
+    }
+
+    pub fn scroll_up(&mut self, rows: usize) {
+        self.offset = self.offset.saturating_sub(rows);
+    }
+
+    pub fn scroll_down(&mut self, rows: usize) {
+        self.offset += rows;
+    }
+
+    pub fn display(&mut self, term_info: &TermInfo) -> Result<(), Error> {
+        // header, blank, and the last line
+        let rows = cmp::max(term_info.height.saturating_sub(3), 1);
+        self.offset = cmp::min(self.offset, self.lines.len().saturating_sub(rows));
+
+        let header = format!(
+            " PID: {}, Lines: {}-{} of {} ( Scroll: Up/Down/PageUp/PageDown, Close: Enter or Esc )",
+            self.pid,
+            self.offset + 1,
+            cmp::min(self.offset + rows, self.lines.len()),
+            self.lines.len()
+        );
+        term_info.write_line(&format!(
+            "{}",
+            console::style(truncate(&header, term_info.width))
+                .white()
+                .bold()
+                .underlined()
+        ))?;
+        term_info.write_line("")?;
+
+        for line in self.lines.iter().skip(self.offset).take(rows) {
+            let line = truncate(line, term_info.width);
+            if line.starts_with(' ') {
+                term_info.write_line(&line)?;
+            } else {
+                term_info.write_line(&format!("{}", console::style(line).white().bold()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn extra_info(proc: &ProcessInfo) -> Vec<(&'static str, Vec<String>)> {
+    use procfs::process::{FDInfo, FDTarget};
+
+    let mut ret = Vec::new();
+
+    let cmdline = proc.curr_proc.cmdline().unwrap_or_default();
+    ret.push(("Command Line", cmdline));
+
+    let mut environ: Vec<String> = proc
+        .curr_proc
+        .environ()
+        .unwrap_or_default()
+        .iter()
+        .map(|(k, v)| format!("{}={}", k.to_string_lossy(), v.to_string_lossy()))
+        .collect();
+    environ.sort();
+    ret.push(("Environment", environ));
+
+    let cwd = proc
+        .curr_proc
+        .cwd()
+        .map(|x| x.to_string_lossy().to_string());
+    ret.push(("Cwd", cwd.into_iter().collect()));
+
+    let exe = proc
+        .curr_proc
+        .exe()
+        .map(|x| x.to_string_lossy().to_string());
+    ret.push(("Exe", exe.into_iter().collect()));
+
+    let mut fds: Vec<&FDInfo> = proc.curr_fds.iter().flatten().collect();
+    fds.sort_by_key(|x| x.fd);
+    let fds = fds
+        .iter()
+        .map(|x| {
+            let target = match &x.target {
+                FDTarget::Path(x) => x.to_string_lossy().to_string(),
+                FDTarget::Socket(x) => format!("socket:[{}]", x),
+                FDTarget::Net(x) => format!("net:[{}]", x),
+                FDTarget::Pipe(x) => format!("pipe:[{}]", x),
+                FDTarget::AnonInode(x) => format!("anon_inode:{}", x),
+                FDTarget::MemFD(x) => format!("memfd:{}", x),
+                FDTarget::Other(x, y) => format!("{}:[{}]", x, y),
+            };
+            format!("{} {}", x.fd, target)
+        })
+        .collect();
+    ret.push(("File Descriptors", fds));
+
+    let cgroups = proc
+        .curr_proc
+        .cgroups()
+        .unwrap_or_default()
+        .iter()
+        .map(|x| format!("{}:{}:{}", x.hierarchy, x.controllers.join(","), x.pathname))
+        .collect();
+    ret.push(("Cgroup", cgroups));
+
+    let mut namespaces = Vec::new();
+    for ns in &["cgroup", "ipc", "mnt", "net", "pid", "time", "user", "uts"] {
+        let path = format!("/proc/{}/ns/{}", proc.pid, ns);
+        if let Ok(x) = std::fs::read_link(path) {
+            namespaces.push(x.to_string_lossy().to_string());
+        }
+    }
+    ret.push(("Namespaces", namespaces));
+
+    // The kernel stack is readable by root only, and omitted otherwise
+    let path = format!("/proc/{}/stack", proc.pid);
+    if let Ok(x) = std::fs::read_to_string(path) {
+        ret.push(("Kernel Stack", x.lines().map(String::from).collect()));
+    }
+
+    ret
+}
+
+#[cfg(not(target_os = "linux"))]
+fn extra_info(_proc: &ProcessInfo) -> Vec<(&'static str, Vec<String>)> {
+    Vec::new()
+}
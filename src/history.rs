@@ -0,0 +1,60 @@
+use crate::columns::ConfigColumnKind;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub struct History {
+    len: usize,
+    values: HashMap<(ConfigColumnKind, i32), VecDeque<f64>>,
+}
+
+impl History {
+    pub fn new(len: usize) -> Self {
+        History {
+            len,
+            values: HashMap::new(),
+        }
+    }
+
+    pub fn push(&mut self, kind: &ConfigColumnKind, pid: i32, value: f64) {
+        let len = self.len;
+        let values = self.values.entry((kind.clone(), pid)).or_default();
+        values.push_back(value);
+        while values.len() > len {
+            values.pop_front();
+        }
+    }
+
+    pub fn get(&self, kind: &ConfigColumnKind, pid: i32) -> Option<&VecDeque<f64>> {
+        self.values.get(&(kind.clone(), pid))
+    }
+
+    pub fn retain(&mut self, pids: &[i32]) {
+        let pids: HashSet<_> = pids.iter().collect();
+        self.values.retain(|(_, pid), _| pids.contains(pid));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history() {
+        let mut history = History::new(3);
+        for i in 0..5 {
+            history.push(&ConfigColumnKind::UsageCpu, 1, i as f64);
+        }
+        history.push(&ConfigColumnKind::UsageCpu, 2, 10.0);
+
+        let values: Vec<_> = history
+            .get(&ConfigColumnKind::UsageCpu, 1)
+            .unwrap()
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(values, vec![2.0, 3.0, 4.0]);
+
+        history.retain(&[2]);
+        assert!(history.get(&ConfigColumnKind::UsageCpu, 1).is_none());
+        assert!(history.get(&ConfigColumnKind::UsageCpu, 2).is_some());
+    }
+}
@@ -0,0 +1,190 @@
+use anyhow::Error;
+use getch::Getch;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Key {
+    Char(char),
+    Enter,
+    Esc,
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Unknown,
+}
+
+// Getch::getch can't handle escape sequences of special keys,
+// so Getch is used to switch terminal mode only and stdin is read directly.
+pub struct KeyReader {
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    getch: Getch,
+    // An escape sequence may be split across reads over slow connections like SSH
+    #[cfg(not(target_os = "windows"))]
+    pending: Vec<u8>,
+}
+
+// Wait time for the rest of a partial escape sequence before it is taken as typed
+#[cfg(not(target_os = "windows"))]
+const ESC_TIMEOUT_MS: i32 = 50;
+
+#[cfg_attr(tarpaulin, skip)]
+impl KeyReader {
+    pub fn new() -> Self {
+        KeyReader {
+            getch: Getch::new(),
+            #[cfg(not(target_os = "windows"))]
+            pending: Vec::new(),
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    pub fn read(&mut self) -> Result<Vec<Key>, Error> {
+        let mut buf = [0u8; 64];
+        loop {
+            if !self.pending.is_empty() && !wait_input(ESC_TIMEOUT_MS) {
+                let (keys, _) = parse(&self.pending, true);
+                self.pending.clear();
+                return Ok(keys);
+            }
+            let len = unsafe { libc::read(0, buf.as_mut_ptr() as *mut libc::c_void, buf.len()) };
+            if len < 0 {
+                return Err(std::io::Error::last_os_error().into());
+            }
+            if len == 0 {
+                let (keys, _) = parse(&self.pending, true);
+                self.pending.clear();
+                return Ok(keys);
+            }
+            self.pending.extend_from_slice(&buf[0..len as usize]);
+            let (keys, used) = parse(&self.pending, false);
+            self.pending.drain(0..used);
+            if !keys.is_empty() {
+                return Ok(keys);
+            }
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    pub fn read(&mut self) -> Result<Vec<Key>, Error> {
+        let key = match self.getch.getch()? {
+            13 => Key::Enter,
+            27 => Key::Esc,
+            // Some keypad and function keys are prefixed by 0 instead of 224
+            0 | 224 => match self.getch.getch()? {
+                72 => Key::Up,
+                80 => Key::Down,
+                75 => Key::Left,
+                77 => Key::Right,
+                73 => Key::PageUp,
+                81 => Key::PageDown,
+                71 => Key::Home,
+                79 => Key::End,
+                _ => Key::Unknown,
+            },
+            x => Key::Char(char::from(x)),
+        };
+        Ok(vec![key])
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn wait_input(timeout_ms: i32) -> bool {
+    let mut fds = libc::pollfd {
+        fd: 0,
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    unsafe { libc::poll(&mut fds, 1, timeout_ms) > 0 }
+}
+
+// A read from terminal may contain some keys if they are typed quickly.
+// If `complete` is false, a partial escape sequence at the end is left unparsed,
+// and the returned length is the number of bytes consumed.
+pub fn parse(buf: &[u8], complete: bool) -> (Vec<Key>, usize) {
+    let mut ret = Vec::new();
+    let mut i = 0;
+    while i < buf.len() {
+        if !complete && buf[i] == 0x1b && i + 1 == buf.len() {
+            break;
+        }
+        let key = match buf[i] {
+            0x1b if i + 1 < buf.len() && (buf[i + 1] == b'[' || buf[i + 1] == b'O') => {
+                // CSI/SS3 sequence is terminated by a byte in 0x40..=0x7e
+                let start = i + 2;
+                let mut end = start;
+                while end < buf.len() && !(0x40..=0x7e).contains(&buf[end]) {
+                    end += 1;
+                }
+                if end >= buf.len() {
+                    if !complete {
+                        break;
+                    }
+                    i = buf.len();
+                    ret.push(Key::Unknown);
+                    continue;
+                }
+                let param = &buf[start..end];
+                let key = match (param, buf[end]) {
+                    (_, b'A') => Key::Up,
+                    (_, b'B') => Key::Down,
+                    (_, b'C') => Key::Right,
+                    (_, b'D') => Key::Left,
+                    (_, b'H') => Key::Home,
+                    (_, b'F') => Key::End,
+                    (b"1", b'~') | (b"7", b'~') => Key::Home,
+                    (b"4", b'~') | (b"8", b'~') => Key::End,
+                    (b"5", b'~') => Key::PageUp,
+                    (b"6", b'~') => Key::PageDown,
+                    _ => Key::Unknown,
+                };
+                i = end;
+                key
+            }
+            0x1b => Key::Esc,
+            b'\r' | b'\n' => Key::Enter,
+            x => Key::Char(char::from(x)),
+        };
+        ret.push(key);
+        i += 1;
+    }
+    (ret, i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_all(buf: &[u8]) -> Vec<Key> {
+        parse(buf, true).0
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_all(b"q"), vec![Key::Char('q')]);
+        assert_eq!(parse_all(b"\x1b"), vec![Key::Esc]);
+        assert_eq!(parse_all(b"\x1b[A\x1bOB"), vec![Key::Up, Key::Down]);
+        assert_eq!(
+            parse_all(b"\x1b[5~\x1b[6~"),
+            vec![Key::PageUp, Key::PageDown]
+        );
+        assert_eq!(
+            parse_all(b"\x1b[H\x1b[4~n"),
+            vec![Key::Home, Key::End, Key::Char('n')]
+        );
+        assert_eq!(parse_all(b"\x1b[1;5C"), vec![Key::Right]);
+        assert_eq!(parse_all(b"\x1b[2"), vec![Key::Unknown]);
+    }
+
+    #[test]
+    fn test_parse_partial() {
+        assert_eq!(parse(b"q\x1b", false), (vec![Key::Char('q')], 1));
+        assert_eq!(parse(b"q\x1b[", false), (vec![Key::Char('q')], 1));
+        assert_eq!(parse(b"\x1b[5", false), (vec![], 0));
+        assert_eq!(parse(b"\x1b[5~", false), (vec![Key::PageUp], 4));
+        assert_eq!(parse(b"\x1b", true), (vec![Key::Esc], 1));
+    }
+}
@@ -1,7 +1,14 @@
+#[cfg(target_os = "linux")]
+mod cgroup;
 mod column;
 mod columns;
 mod config;
+mod detail;
+mod history;
+mod key;
+mod picker;
 mod process;
+mod snapshot;
 mod style;
 mod term_info;
 mod util;
@@ -16,10 +23,12 @@ use crate::view::View;
 use crate::watcher::Watcher;
 use anyhow::{anyhow, Context, Error};
 use console::Term;
+use serde_derive::Serialize;
 use std::cmp;
 use std::collections::HashMap;
 use std::fs;
 use std::io::Read;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::time::Instant;
 use structopt::{clap, StructOpt};
@@ -172,7 +181,7 @@ pub struct Opt {
 // ---------------------------------------------------------------------------------------------------------------------
 
 #[cfg_attr(tarpaulin, skip)]
-fn get_config() -> Result<Config, Error> {
+fn get_config_path() -> Option<PathBuf> {
     let dot_cfg_path = directories::BaseDirs::new()
         .map(|base| base.home_dir().join(".procs.toml"))
         .filter(|path| path.exists());
@@ -187,7 +196,11 @@ fn get_config() -> Result<Config, Error> {
                 .join("config.toml")
         })
         .filter(|path| path.exists());
-    let cfg_path = dot_cfg_path.or(app_cfg_path).or(xdg_cfg_path);
+    dot_cfg_path.or(app_cfg_path).or(xdg_cfg_path)
+}
+
+fn get_config() -> Result<Config, Error> {
+    let cfg_path = get_config_path();
 
     let config: Config = if let Some(path) = cfg_path {
         let mut f = fs::File::open(&path).context(format!("failed to open file ({:?})", path))?;
@@ -202,6 +215,62 @@ fn get_config() -> Result<Config, Error> {
     Ok(config)
 }
 
+#[derive(Serialize)]
+struct ConfigColumns<'a> {
+    columns: &'a [ConfigColumn],
+}
+
+// If there is no configuration file, it is created at the OS-specific location.
+// Otherwise only `[[columns]]` is replaced to keep comments and the other sections.
+#[cfg_attr(tarpaulin, skip)]
+pub fn save_config(config: &Config) -> Result<PathBuf, Error> {
+    let path = if let Some(path) = get_config_path() {
+        path
+    } else {
+        let dir = directories::ProjectDirs::from("com.github", "dalance", "procs")
+            .map(|proj| proj.config_dir().to_path_buf())
+            .ok_or_else(|| anyhow!("failed to get config directory"))?;
+        fs::create_dir_all(&dir).context(format!("failed to create directory ({:?})", dir))?;
+        dir.join("config.toml")
+    };
+
+    let current = if path.exists() {
+        Some(fs::read_to_string(&path).context(format!("failed to read file ({:?})", path))?)
+    } else {
+        None
+    };
+    let toml = config_to_toml(current.as_deref(), config)?;
+    fs::write(&path, toml).context(format!("failed to write file ({:?})", path))?;
+    Ok(path)
+}
+
+fn config_to_toml(current: Option<&str>, config: &Config) -> Result<String, Error> {
+    if let Some(current) = current {
+        replace_columns(current, &config.columns)
+    } else {
+        Ok(toml::to_string(config)?)
+    }
+}
+
+fn replace_columns(current: &str, columns: &[ConfigColumn]) -> Result<String, Error> {
+    let mut doc: toml_edit::Document = current.parse()?;
+    let new: toml_edit::Document = toml::to_string(&ConfigColumns { columns })?.parse()?;
+    let mut new = new["columns"].clone();
+
+    // The comment before the first column and the position of the section are kept
+    let old = doc.get("columns").and_then(|x| x.as_array_of_tables());
+    let old = old.and_then(|x| x.get(0));
+    let new_tables = new.as_array_of_tables_mut();
+    if let (Some(old), Some(new)) = (old, new_tables.and_then(|x| x.get_mut(0))) {
+        *new.decor_mut() = old.decor().clone();
+        if let Some(x) = old.position() {
+            new.set_position(x);
+        }
+    }
+    doc["columns"] = new;
+    Ok(doc.to_string())
+}
+
 // ---------------------------------------------------------------------------------------------------------------------
 // Main
 // ---------------------------------------------------------------------------------------------------------------------
@@ -291,7 +360,7 @@ fn run_watch(opt: &Opt, config: &Config, interval: u64) -> Result<(), Error> {
 fn run_default(opt: &Opt, config: &Config) -> Result<(), Error> {
     let mut time = Instant::now();
 
-    let mut view = View::new(opt, config, false)?;
+    let mut view = View::new(opt, config, false, None)?;
 
     if opt.debug {
         lap(&mut time, "Info: View::new");
@@ -348,6 +417,11 @@ mod tests {
         let ret = run_default(&opt, &config);
         assert!(ret.is_ok());
 
+        let args = ["procs", "pid:1", "Command:init"];
+        let opt = Opt::from_iter(args.iter());
+        let ret = run_default(&opt, &config);
+        assert!(ret.is_ok());
+
         let args = vec!["procs", "--or", "root", "1"];
         let opt = Opt::from_iter(args.iter());
         let ret = run_default(&opt, &config);
@@ -451,4 +525,45 @@ mod tests {
         let ret = run_default(&opt, &config);
         assert!(ret.is_ok());
     }
+
+    #[test]
+    fn test_replace_columns() {
+        let current = r#"# my config
+[[columns]]
+kind = "Pid"
+style = "BrightYellow"
+
+[display]
+# keep this
+show_self = true
+"#;
+        let config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        let columns = &config.columns[0..2];
+        let ret = replace_columns(current, columns).unwrap();
+        assert!(ret.starts_with("# my config\n"));
+        assert!(ret.contains("# keep this\nshow_self = true"));
+        assert!(!ret.contains("[sort]"));
+
+        let ret: Config = toml::from_str(&ret).unwrap();
+        assert_eq!(ret.columns.len(), 2);
+        assert_eq!(ret.columns[1].kind, columns[1].kind);
+        assert!(ret.display.show_self);
+    }
+
+    #[test]
+    fn test_config_to_toml_new() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        config.columns.truncate(2);
+        config.display.show_self = true;
+        let ret = config_to_toml(None, &config).unwrap();
+
+        let ret: Config = toml::from_str(&ret).unwrap();
+        assert_eq!(ret.columns.len(), 2);
+        assert_eq!(ret.columns[0].kind, config.columns[0].kind);
+        assert!(ret.columns[0].numeric_search);
+        assert_eq!(ret.columns[1].kind, config.columns[1].kind);
+        assert!(ret.columns[1].nonnumeric_search);
+        assert!(ret.display.show_self);
+        assert_eq!(ret.watch.history_length, config.watch.history_length);
+    }
 }
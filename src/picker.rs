@@ -0,0 +1,234 @@
+use crate::columns::{ConfigColumnKind, CONFIG_ALL, CONFIG_DEFAULT, KIND_LIST};
+use crate::config::{Config, ConfigColumn, ConfigColumnAlign, ConfigColumnStyle};
+use crate::term_info::TermInfo;
+use crate::util::{adjust, truncate};
+use anyhow::Error;
+use std::cmp;
+use unicode_width::UnicodeWidthStr;
+
+pub struct Picker {
+    items: Vec<(ConfigColumn, bool)>,
+    cursor: usize,
+    pub changed: bool,
+    pub message: Option<String>,
+}
+
+impl Picker {
+    pub fn new(columns: &[ConfigColumn]) -> Self {
+        let mut items: Vec<(ConfigColumn, bool)> =
+            columns.iter().map(|x| (x.clone(), true)).collect();
+
+        // Layout-only kinds can be used any number of times, so they are not listed as hidden
+        let mut hidden: Vec<&ConfigColumnKind> = KIND_LIST
+            .keys()
+            .filter(|x| {
+                !matches!(
+                    x,
+                    ConfigColumnKind::Empty | ConfigColumnKind::Separator | ConfigColumnKind::Slot
+                )
+            })
+            .filter(|x| columns.iter().all(|c| &c.kind != *x))
+            .collect();
+        hidden.sort_by_key(|x| KIND_LIST[x].0);
+
+        // Style, align and search of hidden columns are taken from the default and sample configs
+        let templates: Vec<ConfigColumn> = [CONFIG_DEFAULT, CONFIG_ALL]
+            .iter()
+            .filter_map(|x| toml::from_str::<Config>(x).ok())
+            .flat_map(|x| x.columns)
+            .collect();
+        for kind in hidden {
+            let column = if let Some(x) = templates.iter().find(|x| &x.kind == kind) {
+                x.clone()
+            } else {
+                ConfigColumn {
+                    kind: kind.clone(),
+                    style: ConfigColumnStyle::BrightWhite,
+                    numeric_search: false,
+                    nonnumeric_search: false,
+                    align: ConfigColumnAlign::Left,
+                    max_width: None,
+                    min_width: None,
+                    header: None,
+                    env: None,
+                    rate: false,
+                }
+            };
+            items.push((column, false));
+        }
+
+        Picker {
+            items,
+            cursor: 0,
+            changed: false,
+            message: None,
+        }
+    }
+
+    pub fn columns(&self) -> Vec<ConfigColumn> {
+        self.items
+            .iter()
+            .filter(|(_, shown)| *shown)
+            .map(|(x, _)| x.clone())
+            .collect()
+    }
+
+    pub fn up(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn down(&mut self) {
+        self.cursor = cmp::min(self.cursor + 1, self.items.len() - 1);
+    }
+
+    pub fn toggle(&mut self) {
+        let shown = self.items[self.cursor].1;
+        // At least one column is required to show the view
+        if shown && self.items.iter().filter(|(_, x)| *x).count() == 1 {
+            return;
+        }
+        self.items[self.cursor].1 = !shown;
+        self.changed = true;
+    }
+
+    pub fn move_up(&mut self) {
+        if self.cursor > 0 {
+            self.items.swap(self.cursor, self.cursor - 1);
+            self.cursor -= 1;
+            self.changed = true;
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        if self.cursor + 1 < self.items.len() {
+            self.items.swap(self.cursor, self.cursor + 1);
+            self.cursor += 1;
+            self.changed = true;
+        }
+    }
+
+    pub fn display(&self, term_info: &TermInfo) -> Result<(), Error> {
+        let header = " Columns ( Select: Up/Down, Show/Hide: Space, Move: Left/Right, Save: s, Close: c or Esc )";
+        term_info.write_line(&format!(
+            "{}",
+            console::style(truncate(header, term_info.width))
+                .white()
+                .bold()
+                .underlined()
+        ))?;
+        if let Some(ref message) = self.message {
+            term_info.write_line(&truncate(&format!(" {}", message), term_info.width))?;
+        } else {
+            term_info.write_line("")?;
+        }
+
+        let width = KIND_LIST
+            .values()
+            .map(|(x, _)| UnicodeWidthStr::width(*x))
+            .max()
+            .unwrap_or(0);
+
+        // header, message, and the last line
+        let rows = cmp::max(term_info.height.saturating_sub(3), 1);
+        let offset = (self.cursor / rows) * rows;
+        for (i, (column, shown)) in self.items.iter().enumerate().skip(offset).take(rows) {
+            let (name, desc) = KIND_LIST[&column.kind];
+            let line = format!(
+                " [{}] {} {}",
+                if *shown { "x" } else { " " },
+                adjust(name, width, &ConfigColumnAlign::Left),
+                desc
+            );
+            let line = truncate(&line, term_info.width).to_string();
+            let line = if i == self.cursor {
+                console::style(line).reverse()
+            } else if *shown {
+                console::style(line).white().bright()
+            } else {
+                console::style(line).white()
+            };
+            term_info.write_line(&format!("{}", line))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(columns: &[ConfigColumn]) -> Vec<ConfigColumnKind> {
+        columns.iter().map(|x| x.kind.clone()).collect()
+    }
+
+    #[test]
+    fn test_picker() {
+        let config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        let columns = &config.columns[0..3];
+        let mut picker = Picker::new(columns);
+        // Empty, Separator and Slot are listed only if they are shown
+        assert_eq!(picker.items.len(), KIND_LIST.len() - 2);
+        assert_eq!(kinds(&picker.columns()), kinds(columns));
+        assert!(!picker.changed);
+
+        // The first column can't be moved up
+        picker.move_up();
+        assert!(!picker.changed);
+        picker.move_down();
+        assert_eq!(picker.cursor, 1);
+        assert_eq!(
+            kinds(&picker.columns()),
+            [
+                columns[1].kind.clone(),
+                columns[0].kind.clone(),
+                columns[2].kind.clone()
+            ]
+        );
+
+        // The last item can't be moved down
+        let last = picker.items.len() - 1;
+        picker.cursor = last;
+        let last_kind = picker.items[last].0.kind.clone();
+        picker.move_down();
+        assert_eq!(picker.cursor, last);
+        assert_eq!(picker.items[last].0.kind, last_kind);
+
+        // The hidden column is appended to the shown ones
+        picker.toggle();
+        assert_eq!(picker.columns().len(), 4);
+        assert_eq!(picker.columns()[3].kind, last_kind);
+        picker.toggle();
+        assert_eq!(picker.columns().len(), 3);
+    }
+
+    #[test]
+    fn test_picker_template() {
+        let config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        let picker = Picker::new(&config.columns[0..1]);
+        let (column, _) = picker
+            .items
+            .iter()
+            .find(|(x, _)| x.kind == ConfigColumnKind::Command)
+            .unwrap();
+        assert!(matches!(column.style, ConfigColumnStyle::BrightWhite));
+        assert!(column.nonnumeric_search);
+        let (column, _) = picker
+            .items
+            .iter()
+            .find(|(x, _)| x.kind == ConfigColumnKind::UsageCpu)
+            .unwrap();
+        assert!(matches!(column.style, ConfigColumnStyle::ByPercentage));
+        assert!(matches!(column.align, ConfigColumnAlign::Right));
+    }
+
+    #[test]
+    fn test_picker_toggle_last() {
+        let config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        let mut picker = Picker::new(&config.columns[0..1]);
+
+        // At least one column is kept
+        picker.toggle();
+        assert_eq!(kinds(&picker.columns()), kinds(&config.columns[0..1]));
+        assert!(!picker.changed);
+    }
+}
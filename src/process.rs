@@ -11,3 +11,15 @@ pub use self::linux::*;
 pub use self::macos::*;
 #[cfg(target_os = "windows")]
 pub use self::windows::*;
+
+// Expensive information is collected only if it is required by columns
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CollectOption {
+    pub socket: bool,
+    pub fd: bool,
+    pub schedstat: bool,
+    pub blkio_delay: bool,
+    pub prev_status: bool,
+    // All information of the process is collected for the detail view
+    pub detail: Option<i32>,
+}
@@ -1,4 +1,12 @@
-use procfs::process::{Io, Process, Status};
+use super::CollectOption;
+use procfs::net::{TcpNetEntry, UdpNetEntry, UnixNetEntry, UnixState};
+use procfs::process::{FDInfo, FDTarget, Io, Process, Schedstat, Status};
+use procfs::ProcResult;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::rc::Rc;
 use std::thread;
 use std::time::{Duration, Instant};
 
@@ -9,32 +17,218 @@ pub struct ProcessInfo {
     pub prev_proc: Process,
     pub curr_io: Option<Io>,
     pub prev_io: Option<Io>,
+    pub curr_schedstat: Option<Schedstat>,
+    pub prev_schedstat: Option<Schedstat>,
+    pub curr_blkio_delay: Option<u64>,
+    pub prev_blkio_delay: Option<u64>,
     pub curr_status: Option<Status>,
+    pub prev_status: Option<Status>,
+    pub curr_fds: Option<Vec<FDInfo>>,
+    pub curr_socks: Option<Vec<u32>>,
+    pub socket_index: Rc<SocketIndex>,
     pub interval: Duration,
 }
 
-pub fn collect_proc(interval: Duration) -> Vec<ProcessInfo> {
+// Socket tables indexed by inode, which are shared by all processes of a snapshot
+#[derive(Default)]
+pub struct SocketIndex {
+    pub tcp: HashMap<u32, TcpNetEntry>,
+    pub udp: HashMap<u32, UdpNetEntry>,
+    pub unix: HashMap<u32, UnixNetEntry>,
+}
+
+impl SocketIndex {
+    // The tables under /proc/<pid>/net are of the network namespace of the process
+    fn new(pid: &str) -> Self {
+        let mut index = SocketIndex::default();
+        let tcp = read_table(pid, "tcp", procfs::net::read_tcp_table);
+        let tcp6 = read_table(pid, "tcp6", procfs::net::read_tcp_table);
+        for x in tcp.into_iter().chain(tcp6) {
+            index.tcp.insert(x.inode, x);
+        }
+        let udp = read_table(pid, "udp", procfs::net::read_udp_table);
+        let udp6 = read_table(pid, "udp6", procfs::net::read_udp_table);
+        for x in udp.into_iter().chain(udp6) {
+            index.udp.insert(x.inode, x);
+        }
+        for x in read_table(pid, "unix", read_unix_table) {
+            index.unix.insert(x.inode, x);
+        }
+        index
+    }
+}
+
+fn read_table<T>(pid: &str, name: &str, read: fn(BufReader<File>) -> ProcResult<Vec<T>>) -> Vec<T> {
+    let path = format!("/proc/{}/net/{}", pid, name);
+    File::open(path)
+        .ok()
+        .and_then(|x| read(BufReader::new(x)).ok())
+        .unwrap_or_default()
+}
+
+// procfs provides the unix table of procs' own network namespace only
+fn read_unix_table<R: Read>(reader: BufReader<R>) -> ProcResult<Vec<UnixNetEntry>> {
+    let mut ret = Vec::new();
+    for line in reader.lines().skip(1) {
+        let line = line?;
+        if let Some(entry) = parse_unix_entry(&line) {
+            ret.push(entry);
+        }
+    }
+    Ok(ret)
+}
+
+// The line is like "0000000020de0bb2: 00000003 00000000 00000000 0001 03 71970 /run/a b.sock".
+// The path is the rest of the line because it may contain spaces.
+fn parse_unix_entry(line: &str) -> Option<UnixNetEntry> {
+    let mut s = Vec::new();
+    let mut rest = line;
+    for _ in 0..7 {
+        let x = rest.trim_start();
+        let end = x.find(char::is_whitespace).unwrap_or(x.len());
+        s.push(&x[..end]);
+        rest = &x[end..];
+    }
+    let path = rest.strip_prefix(' ').filter(|x| !x.is_empty());
+    Some(UnixNetEntry {
+        ref_count: u32::from_str_radix(s[1], 16).ok()?,
+        socket_type: u16::from_str_radix(s[4], 16).ok()?,
+        state: UnixState::from_u8(u8::from_str_radix(s[5], 16).ok()?)?,
+        inode: s[6].parse().ok()?,
+        path: path.map(PathBuf::from),
+    })
+}
+
+// The link is like "net:[4026531992]"
+pub fn read_ns(pid: &str, ns: &str) -> Option<u64> {
+    let link = std::fs::read_link(format!("/proc/{}/ns/{}", pid, ns)).ok()?;
+    let link = link.to_string_lossy();
+    let start = link.find('[')?;
+    let end = link.find(']')?;
+    link.get(start + 1..end)?.parse().ok()
+}
+
+// /proc/<pid>/schedstat is of the main thread only, so it is summed over all threads
+fn read_schedstat(proc: &Process) -> Option<Schedstat> {
+    let mut ret = Schedstat {
+        sum_exec_runtime: 0,
+        run_delay: 0,
+        pcount: 0,
+    };
+    for task in proc.tasks().ok()?.flatten() {
+        if let Ok(x) = task.schedstat() {
+            ret.sum_exec_runtime += x.sum_exec_runtime;
+            ret.run_delay += x.run_delay;
+            ret.pcount += x.pcount;
+        }
+    }
+    Some(ret)
+}
+
+// delayacct_blkio_ticks of /proc/<pid>/stat is of the main thread only too
+fn read_blkio_delay(proc: &Process) -> Option<u64> {
+    let mut ret = 0;
+    for task in proc.tasks().ok()?.flatten() {
+        if let Ok(x) = task.stat() {
+            ret += x.delayacct_blkio_ticks.unwrap_or(0);
+        }
+    }
+    Some(ret)
+}
+
+fn socket_inodes(fds: &[FDInfo]) -> Vec<u32> {
+    let mut ret = Vec::new();
+    for fd in fds {
+        if let FDTarget::Socket(x) = fd.target {
+            ret.push(x);
+        }
+    }
+    ret
+}
+
+pub fn collect_proc(interval: Duration, option: CollectOption) -> Vec<ProcessInfo> {
     let mut base_procs = Vec::new();
     let mut ret = Vec::new();
 
     if let Ok(all_proc) = procfs::process::all_processes() {
         for proc in all_proc {
+            // All information of the process shown by the detail view is collected
+            let detail = option.detail == Some(proc.pid());
             let io = proc.io().ok();
+            let schedstat = if option.schedstat || detail {
+                read_schedstat(&proc)
+            } else {
+                None
+            };
+            let blkio_delay = if option.blkio_delay || detail {
+                read_blkio_delay(&proc)
+            } else {
+                None
+            };
+            let status = if option.prev_status || detail {
+                proc.status().ok()
+            } else {
+                None
+            };
             let time = Instant::now();
-            base_procs.push((proc.pid(), proc, io, time));
+            base_procs.push((proc.pid(), proc, io, schedstat, blkio_delay, status, time));
         }
     }
 
     thread::sleep(interval);
 
-    for (pid, prev_proc, prev_io, prev_time) in base_procs {
+    // Processes in the same network namespace share the socket index
+    let own_socket_index = if option.socket || option.detail.is_some() {
+        Rc::new(SocketIndex::new("self"))
+    } else {
+        Rc::new(SocketIndex::default())
+    };
+    let mut socket_indexes = HashMap::new();
+    if let Some(x) = read_ns("self", "net") {
+        socket_indexes.insert(x, Rc::clone(&own_socket_index));
+    }
+
+    for (pid, prev_proc, prev_io, prev_schedstat, prev_blkio_delay, prev_status, prev_time) in
+        base_procs
+    {
         let curr_proc = if let Ok(proc) = Process::new(pid) {
             proc
         } else {
             prev_proc.clone()
         };
         let curr_io = curr_proc.io().ok();
+        let detail = option.detail == Some(pid);
+        let curr_schedstat = if option.schedstat || detail {
+            read_schedstat(&curr_proc)
+        } else {
+            None
+        };
+        let curr_blkio_delay = if option.blkio_delay || detail {
+            read_blkio_delay(&curr_proc)
+        } else {
+            None
+        };
         let curr_status = curr_proc.status().ok();
+        // fds are walked once and shared by columns of files and sockets
+        let curr_fds = if option.fd || option.socket || detail {
+            curr_proc.fd().ok()
+        } else {
+            None
+        };
+        let (curr_socks, socket_index) = if option.socket || detail {
+            let pid_str = pid.to_string();
+            let socket_index = if let Some(ns) = read_ns(&pid_str, "net") {
+                let index = socket_indexes
+                    .entry(ns)
+                    .or_insert_with(|| Rc::new(SocketIndex::new(&pid_str)));
+                Rc::clone(index)
+            } else {
+                Rc::clone(&own_socket_index)
+            };
+            (curr_fds.as_deref().map(socket_inodes), socket_index)
+        } else {
+            (None, Rc::clone(&own_socket_index))
+        };
         let curr_time = Instant::now();
         let interval = curr_time - prev_time;
         let ppid = curr_proc.stat.ppid;
@@ -46,7 +240,15 @@ pub fn collect_proc(interval: Duration) -> Vec<ProcessInfo> {
             prev_proc,
             curr_io,
             prev_io,
+            curr_schedstat,
+            prev_schedstat,
+            curr_blkio_delay,
+            prev_blkio_delay,
             curr_status,
+            prev_status,
+            curr_fds,
+            curr_socks,
+            socket_index,
             interval,
         };
 
@@ -55,3 +257,31 @@ pub fn collect_proc(interval: Duration) -> Vec<ProcessInfo> {
 
     ret
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_unix_table() {
+        let s = "Num       RefCount Protocol Flags    Type St Inode Path
+0000000020de0bb2: 00000003 00000000 00000000 0001 03 71970
+000000007f151adc: 00000002 00000000 00010000 0001 01   659 @/tmp/.X11-unix/X0
+0000000021fa9a4c: 00000002 00000000 00010000 0005 01 30822 /run/user/1000/a b.sock
+";
+        let entries = read_unix_table(BufReader::new(s.as_bytes())).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].ref_count, 3);
+        assert_eq!(entries[0].inode, 71970);
+        assert_eq!(entries[0].state, UnixState::CONNECTED);
+        assert_eq!(entries[0].path, None);
+        assert_eq!(entries[1].inode, 659);
+        assert_eq!(entries[1].state, UnixState::UNCONNECTED);
+        assert_eq!(entries[1].path, Some(PathBuf::from("@/tmp/.X11-unix/X0")));
+        assert_eq!(entries[2].socket_type, 5);
+        assert_eq!(
+            entries[2].path,
+            Some(PathBuf::from("/run/user/1000/a b.sock"))
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use super::CollectOption;
 use libc::{c_int, c_void, size_t};
 use libproc::libproc::bsd_info::BSDInfo;
 use libproc::libproc::file_info::{pidfdinfo, ListFDs, ProcFDType};
@@ -26,7 +27,7 @@ pub struct ProcessInfo {
 }
 
 #[cfg_attr(tarpaulin, skip)]
-pub fn collect_proc(interval: Duration) -> Vec<ProcessInfo> {
+pub fn collect_proc(interval: Duration, _option: CollectOption) -> Vec<ProcessInfo> {
     let mut base_procs = Vec::new();
     let mut ret = Vec::new();
     let arg_max = get_arg_max();
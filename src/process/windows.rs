@@ -1,3 +1,4 @@
+use super::CollectOption;
 use chrono::offset::TimeZone;
 use chrono::{Local, NaiveDate};
 use libc::c_void;
@@ -70,7 +71,7 @@ pub struct CpuInfo {
 }
 
 #[cfg_attr(tarpaulin, skip)]
-pub fn collect_proc(interval: Duration) -> Vec<ProcessInfo> {
+pub fn collect_proc(interval: Duration, _option: CollectOption) -> Vec<ProcessInfo> {
     let mut base_procs = Vec::new();
     let mut ret = Vec::new();
 
@@ -0,0 +1,225 @@
+use crate::columns::ConfigColumnKind;
+use crate::config::ColumnInfo;
+use crate::view::View;
+use std::collections::{HashMap, HashSet};
+
+pub struct Snapshot {
+    pids: HashSet<i32>,
+    visible_pids: Vec<i32>,
+    contents: HashMap<(ConfigColumnKind, i32), String>,
+    values: HashMap<(ConfigColumnKind, i32), f64>,
+}
+
+#[derive(Default)]
+pub struct Diff {
+    pub new_pids: HashSet<i32>,
+    pub exited_pids: HashSet<i32>,
+    pub exited_contents: HashMap<(ConfigColumnKind, i32), String>,
+    pub changed: HashSet<(ConfigColumnKind, i32)>,
+}
+
+impl Snapshot {
+    pub fn new(view: &View) -> Self {
+        Snapshot::from_columns(&view.ppids, &view.visible_pids, &view.columns)
+    }
+
+    fn from_columns(
+        ppids: &HashMap<i32, i32>,
+        visible_pids: &[i32],
+        columns: &[ColumnInfo],
+    ) -> Self {
+        let pids: HashSet<i32> = ppids.keys().copied().collect();
+        let visible_pids: Vec<i32> = visible_pids
+            .iter()
+            .filter(|x| pids.contains(x))
+            .copied()
+            .collect();
+
+        let mut contents = HashMap::new();
+        let mut values = HashMap::new();
+        for c in columns {
+            for pid in &visible_pids {
+                if let Some(x) = c.column.display_content(*pid, &c.align) {
+                    contents.insert((c.kind.clone(), *pid), x.trim().to_string());
+                }
+            }
+            for pid in &pids {
+                if let Some(x) = c.column.history_value(*pid) {
+                    values.insert((c.kind.clone(), *pid), x);
+                }
+            }
+        }
+
+        Snapshot {
+            pids,
+            visible_pids,
+            contents,
+            values,
+        }
+    }
+
+    pub fn diff(&self, view: &View, change_ratio: f64) -> Diff {
+        self.diff_columns(&view.ppids, &view.columns, change_ratio)
+    }
+
+    fn diff_columns(
+        &self,
+        ppids: &HashMap<i32, i32>,
+        columns: &[ColumnInfo],
+        change_ratio: f64,
+    ) -> Diff {
+        let mut diff = Diff::default();
+
+        for pid in ppids.keys() {
+            if !self.pids.contains(pid) {
+                diff.new_pids.insert(*pid);
+            }
+        }
+
+        for pid in &self.visible_pids {
+            if !ppids.contains_key(pid) {
+                diff.exited_pids.insert(*pid);
+            }
+        }
+        for ((kind, pid), content) in &self.contents {
+            if diff.exited_pids.contains(pid) {
+                diff.exited_contents
+                    .insert((kind.clone(), *pid), content.clone());
+            }
+        }
+
+        // The margin is relative to the largest value of the column
+        for c in columns {
+            let mut curr = Vec::new();
+            for pid in ppids.keys() {
+                if let Some(x) = c.column.history_value(*pid) {
+                    curr.push((*pid, x));
+                }
+            }
+            let max = curr.iter().map(|(_, x)| x.abs()).fold(0.0, f64::max);
+            if max <= 0.0 {
+                continue;
+            }
+            for (pid, x) in curr {
+                if let Some(prev) = self.values.get(&(c.kind.clone(), pid)) {
+                    if (x - prev).abs() > max * change_ratio {
+                        diff.changed.insert((c.kind.clone(), pid));
+                    }
+                }
+            }
+        }
+
+        diff
+    }
+
+    // Exited processes are placed after the process which was shown above them
+    pub fn merge_exited(&self, visible_pids: &[i32], diff: &Diff) -> Vec<i32> {
+        let mut ret = visible_pids.to_vec();
+        for (i, pid) in self.visible_pids.iter().enumerate() {
+            if !diff.exited_pids.contains(pid) {
+                continue;
+            }
+            let pos = self.visible_pids[..i]
+                .iter()
+                .rev()
+                .find_map(|x| ret.iter().position(|y| y == x))
+                .map(|x| x + 1)
+                .unwrap_or(0);
+            ret.insert(pos, *pid);
+        }
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::Column;
+    use crate::column_default;
+    use crate::config::{ConfigColumnAlign, ConfigColumnStyle};
+    use crate::process::ProcessInfo;
+    use std::cmp;
+
+    struct Value {
+        header: String,
+        unit: String,
+        fmt_contents: HashMap<i32, String>,
+        raw_contents: HashMap<i32, u64>,
+        width: usize,
+    }
+
+    impl Column for Value {
+        fn add(&mut self, _proc: &ProcessInfo) {}
+
+        fn history_value(&self, pid: i32) -> Option<f64> {
+            self.raw_contents.get(&pid).map(|x| *x as f64)
+        }
+
+        column_default!(u64);
+    }
+
+    fn gen_columns(values: &[(i32, u64)]) -> Vec<ColumnInfo> {
+        let mut column = Value {
+            header: String::from(""),
+            unit: String::from(""),
+            fmt_contents: HashMap::new(),
+            raw_contents: HashMap::new(),
+            width: 8,
+        };
+        for (pid, x) in values {
+            column.fmt_contents.insert(*pid, format!("{}", x));
+            column.raw_contents.insert(*pid, *x);
+        }
+        vec![ColumnInfo {
+            column: Box::new(column),
+            kind: ConfigColumnKind::UsageCpu,
+            style: ConfigColumnStyle::White,
+            nonnumeric_search: false,
+            numeric_search: false,
+            align: ConfigColumnAlign::Left,
+            max_width: None,
+            min_width: None,
+        }]
+    }
+
+    fn gen_ppids(pids: &[i32]) -> HashMap<i32, i32> {
+        pids.iter().map(|x| (*x, 0)).collect()
+    }
+
+    #[test]
+    fn test_diff() {
+        let prev = Snapshot::from_columns(
+            &gen_ppids(&[1, 2, 3]),
+            &[1, 2, 3],
+            &gen_columns(&[(1, 100), (2, 50), (3, 10)]),
+        );
+        let curr_columns = gen_columns(&[(1, 100), (2, 80), (4, 10)]);
+        let diff = prev.diff_columns(&gen_ppids(&[1, 2, 4]), &curr_columns, 0.2);
+
+        assert_eq!(diff.new_pids, [4].iter().copied().collect());
+        assert_eq!(diff.exited_pids, [3].iter().copied().collect());
+        assert_eq!(
+            diff.exited_contents
+                .get(&(ConfigColumnKind::UsageCpu, 3))
+                .map(|x| x.as_str()),
+            Some("10")
+        );
+        // 30 is larger than 20% of the largest value 100, and 0 is not
+        assert_eq!(
+            diff.changed,
+            [(ConfigColumnKind::UsageCpu, 2)].iter().cloned().collect()
+        );
+
+        let diff = prev.diff_columns(&gen_ppids(&[1, 2, 4]), &curr_columns, 0.5);
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn test_merge_exited() {
+        let prev = Snapshot::from_columns(&gen_ppids(&[1, 2, 3, 4]), &[1, 2, 3, 4], &[]);
+        let diff = prev.diff_columns(&gen_ppids(&[2, 4, 5]), &[], 0.2);
+
+        // 1 has no row above it, and 3 is placed after 2
+        assert_eq!(prev.merge_exited(&[5, 2, 4], &diff), [1, 5, 2, 3, 4]);
+    }
+}
@@ -63,6 +63,23 @@ fn apply_style_by_percentage(x: String, s: &ConfigStyle, faded: bool) -> StyledO
     }
 }
 
+// Score like oom_score is 0 to 1000, and oom_score_adj is -1000 to 1000.
+// Negative scores are of processes protected from OOM killer.
+fn apply_style_by_score(x: String, s: &ConfigStyle, faded: bool) -> StyledObject<String> {
+    let value: f64 = x.trim().parse().unwrap_or(0.0);
+    if value >= 1000.0 {
+        apply_color(x, &s.by_percentage.color_100, faded)
+    } else if value >= 500.0 {
+        apply_color(x, &s.by_percentage.color_075, faded)
+    } else if value > 0.0 {
+        apply_color(x, &s.by_percentage.color_050, faded)
+    } else if value == 0.0 {
+        apply_color(x, &s.by_percentage.color_025, faded)
+    } else {
+        apply_color(x, &s.by_percentage.color_000, faded)
+    }
+}
+
 pub fn apply_color(x: String, c: &ConfigColor, faded: bool) -> StyledObject<String> {
     if faded {
         match c {
@@ -132,11 +149,21 @@ pub fn apply_style(
         ConfigColumnStyle::White => apply_color(x, &ConfigColor::White, faded),
         ConfigColumnStyle::Color256(c) => apply_color(x, &ConfigColor::Color256(*c), faded),
         ConfigColumnStyle::ByPercentage => apply_style_by_percentage(x, s, faded),
+        ConfigColumnStyle::ByScore => apply_style_by_score(x, s, faded),
         ConfigColumnStyle::ByState => apply_style_by_state(x, s, faded),
         ConfigColumnStyle::ByUnit => apply_style_by_unit(x, s, faded),
     }
 }
 
+// console doesn't provide strikethrough, so the escape sequence is inserted directly
+pub fn apply_strikethrough(x: String) -> StyledObject<String> {
+    if console::colors_enabled() {
+        Style::new().apply_to(format!("\x1b[9m{}\x1b[29m", x))
+    } else {
+        Style::new().apply_to(x)
+    }
+}
+
 pub fn color_to_column_style(c: &ConfigColor) -> ConfigColumnStyle {
     match c {
         ConfigColor::BrightBlack => ConfigColumnStyle::BrightBlack,
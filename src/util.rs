@@ -1,6 +1,6 @@
 use crate::column::Column;
 use crate::columns::{ConfigColumnKind, KIND_LIST};
-use crate::config::{ConfigColumnAlign, ConfigSearchLogic};
+use crate::config::{Config, ConfigColumnAlign, ConfigSearchKind, ConfigSearchLogic};
 use byte_unit::Byte;
 use std::borrow::Cow;
 use std::time::Instant;
@@ -71,6 +71,38 @@ pub fn find_exact<T: AsRef<str>>(
     ret
 }
 
+pub fn find_kind(
+    keyword: &[(Option<&dyn Column>, &str)],
+    pid: i32,
+    config: &Config,
+    logic: &ConfigSearchLogic,
+) -> bool {
+    let mut ret = match logic {
+        ConfigSearchLogic::And => true,
+        ConfigSearchLogic::Or => false,
+        ConfigSearchLogic::Nand => true,
+        ConfigSearchLogic::Nor => false,
+    };
+    for (c, w) in keyword {
+        let kind = match classify(w) {
+            KeywordClass::Numeric => &config.search.numeric_search,
+            KeywordClass::NonNumeric => &config.search.nonnumeric_search,
+        };
+        let hit = match (c, kind) {
+            (Some(c), ConfigSearchKind::Partial) => c.find_partial(pid, w),
+            (Some(c), ConfigSearchKind::Exact) => c.find_exact(pid, w),
+            (None, _) => false,
+        };
+        ret = match logic {
+            ConfigSearchLogic::And => ret & hit,
+            ConfigSearchLogic::Or => ret | hit,
+            ConfigSearchLogic::Nand => ret & hit,
+            ConfigSearchLogic::Nor => ret | hit,
+        };
+    }
+    ret
+}
+
 pub fn classify(keyword: &str) -> KeywordClass {
     let parsed = keyword.parse::<i64>();
     match parsed {
@@ -79,6 +111,16 @@ pub fn classify(keyword: &str) -> KeywordClass {
     }
 }
 
+// Keyword like "unit:nginx.service" is searched in the specified column only
+pub fn split_kind_keyword(keyword: &str) -> Option<(ConfigColumnKind, &str)> {
+    let pos = keyword.find(':')?;
+    let (kind, keyword) = (&keyword[..pos], &keyword[pos + 1..]);
+    KIND_LIST
+        .iter()
+        .find(|(_, (v, _))| v.eq_ignore_ascii_case(kind))
+        .map(|(k, _)| (k.clone(), keyword))
+}
+
 pub fn adjust(x: &str, len: usize, align: &ConfigColumnAlign) -> String {
     if len < UnicodeWidthStr::width(x) {
         String::from(truncate(x, len))
@@ -157,6 +199,33 @@ pub fn truncate(s: &'_ str, width: usize) -> Cow<'_, str> {
     }
 }
 
+pub fn skip(s: &'_ str, width: usize) -> Cow<'_, str> {
+    if width == 0 {
+        return Cow::Borrowed(s);
+    }
+    let mut total_width = 0;
+    let mut buf = String::new();
+    let mut escape = false;
+    for c in s.chars() {
+        if c == '\u{1b}' {
+            escape = true;
+        }
+        if escape {
+            if c == 'm' {
+                escape = false;
+            }
+            buf.push(c);
+            continue;
+        }
+        if total_width >= width {
+            buf.push(c);
+        } else {
+            total_width += UnicodeWidthChar::width(c).unwrap_or(0);
+        }
+    }
+    Cow::Owned(buf)
+}
+
 pub fn find_column_kind(pat: &str) -> Option<ConfigColumnKind> {
     for (k, (v, _)) in KIND_LIST.iter() {
         if v.to_lowercase().find(&pat.to_lowercase()).is_some() {
@@ -224,6 +293,33 @@ pub fn bytify(x: u64) -> String {
         .replace("i", "")
 }
 
+// Signal numbers of x86/arm, which bit N-1 of the mask is signal N
+#[cfg(target_os = "linux")]
+const SIGNAL_NAMES: [&str; 31] = [
+    "HUP", "INT", "QUIT", "ILL", "TRAP", "ABRT", "BUS", "FPE", "KILL", "USR1", "SEGV", "USR2",
+    "PIPE", "ALRM", "TERM", "STKFLT", "CHLD", "CONT", "STOP", "TSTP", "TTIN", "TTOU", "URG",
+    "XCPU", "XFSZ", "VTALRM", "PROF", "WINCH", "IO", "PWR", "SYS",
+];
+
+#[cfg(target_os = "linux")]
+pub fn signal_names(mask: u64) -> String {
+    let mut names = Vec::new();
+    for i in 0..64 {
+        if mask & (1 << i) == 0 {
+            continue;
+        }
+        let signal = i + 1;
+        if let Some(x) = SIGNAL_NAMES.get(i) {
+            names.push(String::from(*x));
+        } else if signal >= 34 {
+            names.push(format!("RTMIN+{}", signal - 34));
+        } else {
+            names.push(format!("{}", signal));
+        }
+    }
+    names.join(",")
+}
+
 pub fn lap(instant: &mut Instant, msg: &str) {
     let period = instant.elapsed();
     eprintln!(
@@ -234,3 +330,17 @@ pub fn lap(instant: &mut Instant, msg: &str) {
     );
     instant.clone_from(&Instant::now());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_signal_names() {
+        assert_eq!(signal_names(0), "");
+        assert_eq!(signal_names(0x0000_0000_0000_4003), "HUP,INT,TERM");
+        assert_eq!(signal_names(0x0000_0002_0000_0200), "USR1,RTMIN+0");
+        assert_eq!(signal_names(0x0000_0001_0000_0000), "33");
+    }
+}
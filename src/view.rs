@@ -1,14 +1,20 @@
 use crate::column::Column;
 use crate::columns::*;
 use crate::config::*;
-use crate::process::collect_proc;
-use crate::style::{apply_color, apply_style, color_to_column_style};
+use crate::history::History;
+use crate::process::{collect_proc, CollectOption, ProcessInfo};
+use crate::snapshot::{Diff, Snapshot};
+use crate::style::{apply_color, apply_strikethrough, apply_style, color_to_column_style};
 use crate::term_info::TermInfo;
-use crate::util::{classify, find_column_kind, find_exact, find_partial, truncate, KeywordClass};
+use crate::util::{
+    adjust, classify, find_column_kind, find_exact, find_kind, find_partial, skip,
+    split_kind_keyword, truncate, KeywordClass,
+};
 use crate::Opt;
 use anyhow::{bail, Error};
 #[cfg(not(target_os = "windows"))]
 use pager::Pager;
+use std::cmp;
 use std::collections::HashMap;
 use std::time::Duration;
 
@@ -19,15 +25,25 @@ pub struct SortInfo {
 
 pub struct View {
     pub columns: Vec<ColumnInfo>,
+    pub search_columns: Vec<(ConfigColumnKind, Box<dyn Column>)>,
     pub term_info: TermInfo,
     pub sort_info: SortInfo,
     pub visible_pids: Vec<i32>,
     pub auxiliary_pids: Vec<i32>,
     pub ppids: HashMap<i32, i32>,
+    pub diff: Diff,
+    pub horizontal_offset: usize,
+    pub selected: Option<i32>,
+    pub procs: Vec<ProcessInfo>,
 }
 
 impl View {
-    pub fn new(opt: &Opt, config: &Config, clear_by_line: bool) -> Result<Self, Error> {
+    pub fn new(
+        opt: &Opt,
+        config: &Config,
+        clear_by_line: bool,
+        detail: Option<i32>,
+    ) -> Result<Self, Error> {
         let mut slot_idx = 0;
         let mut columns = Vec::new();
         if opt.tree {
@@ -39,6 +55,11 @@ impl View {
                 &config.display.separator,
                 config.display.abbr_sid,
                 &config.display.tree_symbols,
+                &[],
+                config.container.resolve_name,
+                config.display.mark_foreign_ns,
+                config.display.decode_signal,
+                false,
             );
             if column.available() {
                 columns.push(ColumnInfo {
@@ -87,6 +108,11 @@ impl View {
                     &config.display.separator,
                     config.display.abbr_sid,
                     &config.display.tree_symbols,
+                    c.env.as_deref().unwrap_or_default(),
+                    config.container.resolve_name,
+                    config.display.mark_foreign_ns,
+                    config.display.decode_signal,
+                    c.rate,
                 );
                 if column.available() {
                     columns.push(ColumnInfo {
@@ -109,12 +135,58 @@ impl View {
             }
         }
 
-        let proc = collect_proc(Duration::from_millis(opt.interval));
+        // Columns specified by keywords like "unit:nginx.service" are collected even if they are not shown
+        let mut search_columns: Vec<(ConfigColumnKind, Box<dyn Column>)> = Vec::new();
+        for k in &opt.keyword {
+            if let Some((kind, _)) = split_kind_keyword(k) {
+                if columns.iter().any(|x| x.kind == kind)
+                    || search_columns.iter().any(|(x, _)| *x == kind)
+                {
+                    continue;
+                }
+                let column = gen_column(
+                    &kind,
+                    None,
+                    &config.docker.path,
+                    &config.display.separator,
+                    config.display.abbr_sid,
+                    &config.display.tree_symbols,
+                    &[],
+                    config.container.resolve_name,
+                    config.display.mark_foreign_ns,
+                    config.display.decode_signal,
+                    false,
+                );
+                if column.available() {
+                    search_columns.push((kind, column));
+                }
+            }
+        }
+
+        let all_columns: Vec<&dyn Column> = columns
+            .iter()
+            .map(|x| x.column.as_ref())
+            .chain(search_columns.iter().map(|(_, x)| x.as_ref()))
+            .collect();
+        let option = CollectOption {
+            socket: all_columns.iter().any(|x| x.require_socket()),
+            fd: all_columns.iter().any(|x| x.require_fd()),
+            schedstat: all_columns.iter().any(|x| x.require_schedstat()),
+            blkio_delay: all_columns.iter().any(|x| x.require_blkio_delay()),
+            prev_status: all_columns.iter().any(|x| x.require_prev_status()),
+            detail,
+        };
+        let proc = collect_proc(Duration::from_millis(opt.interval), option);
         for c in columns.iter_mut() {
             for p in &proc {
                 c.column.add(&p);
             }
         }
+        for (_, c) in search_columns.iter_mut() {
+            for p in &proc {
+                c.add(p);
+            }
+        }
 
         let mut ppids = HashMap::new();
         for p in &proc {
@@ -130,11 +202,16 @@ impl View {
 
         Ok(View {
             columns,
+            search_columns,
             term_info,
             sort_info,
             visible_pids: vec![],
             auxiliary_pids: vec![],
             ppids,
+            diff: Diff::default(),
+            horizontal_offset: 0,
+            selected: None,
+            procs: proc,
         })
     }
 
@@ -152,8 +229,25 @@ impl View {
 
         let mut keyword_nonnumeric = Vec::new();
         let mut keyword_numeric = Vec::new();
+        let mut keyword_kind = Vec::new();
 
         for k in &opt.keyword {
+            if let Some((kind, keyword)) = split_kind_keyword(k) {
+                let column = self
+                    .columns
+                    .iter()
+                    .find(|x| x.kind == kind)
+                    .map(|x| x.column.as_ref())
+                    .or_else(|| {
+                        self.search_columns
+                            .iter()
+                            .find(|(x, _)| *x == kind)
+                            .map(|(_, x)| x.as_ref())
+                    });
+                // The column is not available on the platform, so nothing is matched
+                keyword_kind.push((column, keyword));
+                continue;
+            }
             match classify(k) {
                 KeywordClass::Numeric => keyword_numeric.push(k),
                 KeywordClass::NonNumeric => keyword_nonnumeric.push(k),
@@ -189,6 +283,7 @@ impl View {
                     *pid,
                     &keyword_numeric,
                     &keyword_nonnumeric,
+                    &keyword_kind,
                     cols_numeric.as_slice(),
                     cols_nonnumeric.as_slice(),
                     &config,
@@ -221,16 +316,53 @@ impl View {
             if candidate_pids.contains(pid) {
                 visible_pids.push(*pid);
             }
+        }
 
-            if opt.watch_mode && visible_pids.len() >= self.term_info.height - 5 {
-                break;
-            }
+        for c in self.columns.iter_mut() {
+            c.column.apply_visible(&visible_pids);
         }
 
         self.visible_pids = visible_pids;
         self.auxiliary_pids = auxiliary_pids;
     }
 
+    pub fn apply_snapshot(&mut self, snapshot: &Snapshot, config: &Config) {
+        self.diff = snapshot.diff(self, config.watch.change_ratio);
+        self.visible_pids = snapshot.merge_exited(&self.visible_pids, &self.diff);
+    }
+
+    // Returns the offset clamped by the number of rows
+    pub fn scroll(&mut self, offset: usize, rows: usize) -> usize {
+        let offset = cmp::min(offset, self.visible_pids.len().saturating_sub(rows));
+        self.visible_pids = self
+            .visible_pids
+            .iter()
+            .skip(offset)
+            .take(rows)
+            .copied()
+            .collect();
+        offset
+    }
+
+    // Values are recorded only for columns which show history
+    pub fn record_history(&self, history: &mut History) {
+        for c in self.columns.iter().filter(|x| x.column.require_history()) {
+            for pid in self.ppids.keys() {
+                if let Some(x) = c.column.history_value(*pid) {
+                    history.push(&c.kind, *pid, x);
+                }
+            }
+        }
+        let pids: Vec<i32> = self.ppids.keys().copied().collect();
+        history.retain(&pids);
+    }
+
+    pub fn apply_history(&mut self, history: &History) {
+        for c in self.columns.iter_mut() {
+            c.column.apply_history(&c.kind, history);
+        }
+    }
+
     fn get_ppids(&self, pid: i32) -> Vec<i32> {
         let mut ret = vec![];
         if let Some(x) = self.ppids.get(&pid) {
@@ -249,7 +381,6 @@ impl View {
             } else {
                 None
             };
-            c.column.apply_visible(&self.visible_pids);
             let min_width = min_widths.get(&i).map(|x| Some(*x)).unwrap_or(c.min_width);
             c.column.reset_width(order, &config, c.max_width, min_width);
             for pid in &self.visible_pids {
@@ -361,6 +492,7 @@ impl View {
             );
         }
         row = row.trim_end().to_string();
+        row = skip(&row, self.horizontal_offset).to_string();
         row = truncate(&row, self.term_info.width).to_string();
         self.term_info.write_line(&row)?;
         Ok(())
@@ -376,6 +508,7 @@ impl View {
             );
         }
         row = row.trim_end().to_string();
+        row = skip(&row, self.horizontal_offset).to_string();
         row = truncate(&row, self.term_info.width).to_string();
         self.term_info.write_line(&row)?;
         Ok(())
@@ -383,19 +516,35 @@ impl View {
 
     fn display_content(&self, config: &Config, pid: i32, auxiliary: bool) -> Result<(), Error> {
         let mut row = String::from("");
+        let new = self.diff.new_pids.contains(&pid);
+        let exited = self.diff.exited_pids.contains(&pid);
         for c in &self.columns {
-            row = format!(
-                "{} {}",
-                row,
-                apply_style(
+            let content = if exited {
+                let key = (c.kind.clone(), pid);
+                let content = self.diff.exited_contents.get(&key).map(|x| x.as_str());
+                let content = adjust(content.unwrap_or(""), c.column.get_width(), &c.align);
+                apply_strikethrough(content).dim()
+            } else {
+                let content = apply_style(
                     c.column.display_content(pid, &c.align).unwrap(),
                     &c.style,
                     &config.style,
-                    auxiliary
-                )
-            );
+                    auxiliary,
+                );
+                if self.selected == Some(pid) {
+                    content.on_color256(238)
+                } else if self.diff.changed.contains(&(c.kind.clone(), pid)) {
+                    content.reverse()
+                } else if new {
+                    content.bold().underlined()
+                } else {
+                    content
+                }
+            };
+            row = format!("{} {}", row, content);
         }
         row = row.trim_end().to_string();
+        row = skip(&row, self.horizontal_offset).to_string();
         row = truncate(&row, self.term_info.width).to_string();
         self.term_info.write_line(&row)?;
         Ok(())
@@ -433,10 +582,12 @@ impl View {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn search<T: AsRef<str>>(
         pid: i32,
         keyword_numeric: &[T],
         keyword_nonnumeric: &[T],
+        keyword_kind: &[(Option<&dyn Column>, &str)],
         cols_numeric: &[&dyn Column],
         cols_nonnumeric: &[&dyn Column],
         config: &Config,
@@ -452,11 +603,12 @@ impl View {
             ConfigSearchKind::Partial => find_partial(cols_numeric, pid, keyword_numeric, logic),
             ConfigSearchKind::Exact => find_exact(cols_numeric, pid, keyword_numeric, logic),
         };
+        let ret_kind = find_kind(keyword_kind, pid, config, logic);
         match logic {
-            ConfigSearchLogic::And => ret_nonnumeric & ret_numeric,
-            ConfigSearchLogic::Or => ret_nonnumeric | ret_numeric,
-            ConfigSearchLogic::Nand => !(ret_nonnumeric & ret_numeric),
-            ConfigSearchLogic::Nor => !(ret_nonnumeric | ret_numeric),
+            ConfigSearchLogic::And => ret_nonnumeric & ret_numeric & ret_kind,
+            ConfigSearchLogic::Or => ret_nonnumeric | ret_numeric | ret_kind,
+            ConfigSearchLogic::Nand => !(ret_nonnumeric & ret_numeric & ret_kind),
+            ConfigSearchLogic::Nor => !(ret_nonnumeric | ret_numeric | ret_kind),
         }
     }
 
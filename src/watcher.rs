@@ -1,11 +1,18 @@
 use crate::config::*;
+use crate::detail::Detail;
+use crate::history::History;
+use crate::key::{Key, KeyReader};
+use crate::picker::Picker;
+use crate::snapshot::Snapshot;
 use crate::term_info::TermInfo;
 use crate::view::View;
 use crate::Opt;
 use anyhow::Error;
 use chrono::offset::Local;
-use getch::Getch;
+use std::cmp;
 use std::collections::HashMap;
+#[cfg(target_os = "linux")]
+use std::fs;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::thread;
 use std::time::Duration;
@@ -13,10 +20,7 @@ use std::time::Duration;
 enum Command {
     Wake,
     Sleep,
-    Next,
-    Prev,
-    Ascending,
-    Descending,
+    Key(Key),
     Quit,
 }
 
@@ -26,32 +30,24 @@ pub struct Watcher;
 impl Watcher {
     fn spawn_cmd(tx: Sender<Command>) {
         let _ = thread::spawn(move || {
-            let getch = Getch::new();
-            loop {
-                match getch.getch() {
-                    Ok(x) if char::from(x) == 'q' => {
-                        let _ = tx.send(Command::Quit);
-                        break;
+            let mut reader = KeyReader::new();
+            while let Ok(keys) = reader.read() {
+                for key in keys {
+                    match key {
+                        Key::Char('q') => {
+                            let _ = tx.send(Command::Quit);
+                            return;
+                        }
+                        // On windows, _getch return EXT(0x3) by Ctrl-C
+                        #[cfg(target_os = "windows")]
+                        Key::Char('\u{3}') => {
+                            let _ = tx.send(Command::Quit);
+                            return;
+                        }
+                        x => {
+                            let _ = tx.send(Command::Key(x));
+                        }
                     }
-                    Ok(x) if char::from(x) == 'n' => {
-                        let _ = tx.send(Command::Next);
-                    }
-                    Ok(x) if char::from(x) == 'p' => {
-                        let _ = tx.send(Command::Prev);
-                    }
-                    Ok(x) if char::from(x) == 'a' => {
-                        let _ = tx.send(Command::Ascending);
-                    }
-                    Ok(x) if char::from(x) == 'd' => {
-                        let _ = tx.send(Command::Descending);
-                    }
-                    // On windows, _getch return EXT(0x3) by Ctrl-C
-                    #[cfg(target_os = "windows")]
-                    Ok(x) if x == 3 => {
-                        let _ = tx.send(Command::Quit);
-                        break;
-                    }
-                    _ => (),
                 }
             }
         });
@@ -67,18 +63,31 @@ impl Watcher {
         });
     }
 
-    fn display_header(term_info: &TermInfo, opt: &Opt, interval: u64) -> Result<(), Error> {
+    fn display_header(
+        term_info: &TermInfo,
+        opt: &Opt,
+        interval: u64,
+        rows: (usize, usize, usize),
+        message: &Option<String>,
+    ) -> Result<(), Error> {
+        let (first, last, total) = rows;
         let header = if opt.tree {
             format!(
-                " Interval: {}s, Last Updated: {} ( Quit: q or Ctrl-C )",
+                " Interval: {}s, Last Updated: {}, Rows: {}-{} of {} ( Columns: c, Quit: q or Ctrl-C )",
                 interval,
                 Local::now().format("%Y/%m/%d %H:%M:%S"),
+                first,
+                last,
+                total,
             )
         } else {
             format!(
-                " Interval: {}s, Last Updated: {} ( Next: n, Prev: p, Ascending: a, Descending: d, Quit: q or Ctrl-C )",
+                " Interval: {}s, Last Updated: {}, Rows: {}-{} of {} ( Next: n, Prev: p, Ascending: a, Descending: d, Columns: c, Quit: q or Ctrl-C )",
                 interval,
                 Local::now().format("%Y/%m/%d %H:%M:%S"),
+                first,
+                last,
+                total,
             )
         };
         term_info.write_line(&format!(
@@ -86,11 +95,45 @@ impl Watcher {
             console::style(header).white().bold().underlined()
         ))?;
 
-        term_info.write_line("")?;
+        if let Some(message) = message {
+            term_info.write_line(&format!(" {}", message))?;
+        } else {
+            term_info.write_line("")?;
+        }
         Ok(())
     }
 
+    // The selected process is moved by rows in the current order
+    fn move_selection(pids: &[i32], selected: Option<i32>, delta: isize) -> Option<i32> {
+        let row = pids.iter().position(|x| Some(*x) == selected)? as isize;
+        let row = (row + delta).clamp(0, pids.len() as isize - 1);
+        pids.get(row as usize).copied()
+    }
+
+    #[cfg(target_os = "linux")]
+    fn adjust_oom_score(pid: i32, delta: i32) -> String {
+        let path = format!("/proc/{}/oom_score_adj", pid);
+        let adjust = || -> Result<(i32, i32), Error> {
+            let curr: i32 = fs::read_to_string(&path)?.trim().parse()?;
+            let next = (curr + delta).clamp(-1000, 1000);
+            fs::write(&path, format!("{}", next))?;
+            Ok((curr, next))
+        };
+        match adjust() {
+            Ok((curr, next)) => format!("oom_score_adj of PID {}: {} -> {}", pid, curr, next),
+            Err(x) => format!("Failed to adjust oom_score_adj of PID {}: {}", pid, x),
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn adjust_oom_score(_pid: i32, _delta: i32) -> String {
+        String::from("oom_score_adj is not supported")
+    }
+
     pub fn start(opt: &Opt, config: &Config, interval: u64) -> Result<(), Error> {
+        let mut config = config.clone();
+        let config = &mut config;
+
         let (tx_cmd, rx_cmd) = channel();
         Watcher::spawn_cmd(tx_cmd.clone());
 
@@ -103,10 +146,23 @@ impl Watcher {
         let mut sort_idx = None;
         let mut sort_order = None;
         let mut min_widths = HashMap::new();
+        let mut history = History::new(config.watch.history_length);
+        let mut prev_snapshot: Option<Snapshot> = None;
+        let mut snapshot: Option<Snapshot> = None;
+        let mut offset = 0;
+        let mut horizontal_offset = 0;
+        let mut picker: Option<Picker> = None;
+        let mut detail: Option<Detail> = None;
+        let mut selected: Option<i32> = None;
+        let mut message: Option<String> = None;
         let mut prev_term_width = 0;
         let mut prev_term_height = 0;
+        // The view is rebuilt by periodic updates and column changes only, and key inputs re-use it
+        let mut view = View::new(opt, config, true, None)?;
+        let mut update = true;
+        let mut rebuild = false;
         'outer: loop {
-            let mut view = View::new(opt, config, true)?;
+            view.term_info = TermInfo::new(true);
 
             // Override sort_info by key
             if !opt.tree {
@@ -114,8 +170,62 @@ impl Watcher {
                 view.sort_info.order = sort_order.clone().unwrap_or(view.sort_info.order);
             }
 
+            if update {
+                view.record_history(&mut history);
+            }
+            if update || rebuild {
+                view.apply_history(&history);
+            }
+
             view.filter(opt, config);
+
+            // Compare with the previous periodic update even if the view is re-used by key input.
+            // The snapshot covers all filtered processes, so rows out of the window are compared too.
+            if update {
+                prev_snapshot = snapshot.take();
+                view.adjust(config, &min_widths);
+                snapshot = Some(Snapshot::new(&view));
+            }
+            if config.watch.highlight {
+                if let Some(ref prev_snapshot) = prev_snapshot {
+                    view.apply_snapshot(prev_snapshot, config);
+                }
+            }
+
+            // header, blank, column header, unit, and the last line
+            let rows = view.term_info.height.saturating_sub(5);
+            let total = view.visible_pids.len();
+            // The selection follows the process across re-sorting, and is cleared after it exits
+            if let Some(pid) = selected {
+                if let Some(x) = view.visible_pids.iter().position(|x| *x == pid) {
+                    if x < offset {
+                        offset = x;
+                    } else if x >= offset + rows {
+                        offset = (x + 1).saturating_sub(rows);
+                    }
+                } else {
+                    selected = None;
+                }
+            }
+            view.selected = selected;
+            let pids = view.visible_pids.clone();
+            offset = view.scroll(offset, rows);
+
+            if update || rebuild {
+                if let Some(ref mut detail) = detail {
+                    if let Some(proc) = view.procs.iter().find(|x| x.pid == detail.pid) {
+                        detail.update(proc, config, &history);
+                    }
+                }
+            }
+
             view.adjust(config, &min_widths);
+            let width: usize = view.columns.iter().map(|x| x.column.get_width() + 1).sum();
+            horizontal_offset = cmp::min(
+                horizontal_offset,
+                width.saturating_sub(view.term_info.width),
+            );
+            view.horizontal_offset = horizontal_offset;
             for (i, c) in view.columns.iter().enumerate() {
                 min_widths.insert(i, c.column.get_width());
             }
@@ -125,14 +235,30 @@ impl Watcher {
             if resized {
                 term_info.clear_screen()?;
             }
-            Watcher::display_header(&view.term_info, opt, interval)?;
+            if let Some(ref picker) = picker {
+                picker.display(&view.term_info)?;
+            } else if let Some(ref mut detail) = detail {
+                detail.display(&view.term_info)?;
+            } else {
+                let first = if total == 0 { 0 } else { offset + 1 };
+                let last = offset + view.visible_pids.len();
+                Watcher::display_header(
+                    &view.term_info,
+                    opt,
+                    interval,
+                    (first, last, total),
+                    &message,
+                )?;
 
-            view.display(opt, config)?;
+                view.display(opt, config)?;
+            }
 
             view.term_info.clear_rest_lines()?;
             view.term_info.move_cursor_to(0, 0)?;
 
-            tx_sleep.send(Command::Sleep)?;
+            if update {
+                tx_sleep.send(Command::Sleep)?;
+            }
             let mut cmds = Vec::new();
             if let Ok(cmd) = rx_cmd.recv() {
                 cmds.push(cmd);
@@ -141,6 +267,11 @@ impl Watcher {
                 }
             }
 
+            prev_term_width = view.term_info.width;
+            prev_term_height = view.term_info.height;
+
+            update = false;
+            rebuild = false;
             for cmd in cmds {
                 match cmd {
                     Command::Quit => {
@@ -148,16 +279,119 @@ impl Watcher {
                         view.term_info.clear_screen()?;
                         break 'outer;
                     }
-                    Command::Next => sort_idx = Some(view.inc_sort_column()),
-                    Command::Prev => sort_idx = Some(view.dec_sort_column()),
-                    Command::Ascending => sort_order = Some(ConfigSortOrder::Ascending),
-                    Command::Descending => sort_order = Some(ConfigSortOrder::Descending),
+                    Command::Key(key) if picker.is_some() => {
+                        let p = picker.as_mut().unwrap();
+                        match key {
+                            Key::Up => p.up(),
+                            Key::Down => p.down(),
+                            Key::Left => p.move_up(),
+                            Key::Right => p.move_down(),
+                            Key::Char(' ') => p.toggle(),
+                            Key::Char('s') => {
+                                let mut config = config.clone();
+                                config.columns = p.columns();
+                                p.message = match crate::save_config(&config) {
+                                    Ok(path) => Some(format!("Saved to {}", path.display())),
+                                    Err(x) => Some(format!("Failed to save: {}", x)),
+                                };
+                            }
+                            Key::Char('c') | Key::Esc => {
+                                if p.changed {
+                                    config.columns = p.columns();
+                                    // Column indexes are changed
+                                    sort_idx = None;
+                                    if config.sort.column >= config.columns.len() {
+                                        config.sort.column = 0;
+                                    }
+                                    min_widths.clear();
+                                    rebuild = true;
+                                }
+                                picker = None;
+                                term_info.clear_screen()?;
+                            }
+                            _ => (),
+                        }
+                    }
+                    Command::Key(key) if detail.is_some() => {
+                        let d = detail.as_mut().unwrap();
+                        let rows = view.term_info.height.saturating_sub(3);
+                        match key {
+                            Key::Up => d.scroll_up(1),
+                            Key::Down => d.scroll_down(1),
+                            Key::PageUp => d.scroll_up(rows),
+                            Key::PageDown => d.scroll_down(rows),
+                            Key::Enter | Key::Esc => {
+                                detail = None;
+                                term_info.clear_screen()?;
+                            }
+                            _ => (),
+                        }
+                    }
+                    Command::Key(key) => match key {
+                        Key::Char(x @ '+') | Key::Char(x @ '-') => {
+                            let delta = if x == '+' { 100 } else { -100 };
+                            message = if let Some(pid) = selected {
+                                // The view is rebuilt to show the new value
+                                rebuild = true;
+                                Some(Watcher::adjust_oom_score(pid, delta))
+                            } else {
+                                Some(String::from("Select a process by Up/Down"))
+                            };
+                        }
+                        Key::Char('n') => sort_idx = Some(view.inc_sort_column()),
+                        Key::Char('p') => sort_idx = Some(view.dec_sort_column()),
+                        Key::Char('a') => sort_order = Some(ConfigSortOrder::Ascending),
+                        Key::Char('d') => sort_order = Some(ConfigSortOrder::Descending),
+                        Key::Char('c') => {
+                            picker = Some(Picker::new(&config.columns));
+                            term_info.clear_screen()?;
+                        }
+                        Key::Up if selected.is_none() => selected = pids.get(offset).copied(),
+                        Key::Down if selected.is_none() => selected = pids.get(offset).copied(),
+                        Key::Up => selected = Watcher::move_selection(&pids, selected, -1),
+                        Key::Down => selected = Watcher::move_selection(&pids, selected, 1),
+                        Key::Enter => {
+                            let pid = selected.or_else(|| view.visible_pids.first().copied());
+                            if let Some(pid) = pid {
+                                detail = Some(Detail::new(pid, config));
+                                // The view is rebuilt to collect all information of the process
+                                rebuild = true;
+                                term_info.clear_screen()?;
+                            }
+                        }
+                        Key::Esc => selected = None,
+                        Key::PageUp => {
+                            offset = offset.saturating_sub(rows);
+                            selected = Watcher::move_selection(&pids, selected, -(rows as isize));
+                        }
+                        Key::PageDown => {
+                            offset += rows;
+                            selected = Watcher::move_selection(&pids, selected, rows as isize);
+                        }
+                        Key::Home => {
+                            offset = 0;
+                            selected = selected.and_then(|_| pids.first().copied());
+                        }
+                        Key::End => {
+                            offset = total;
+                            selected = selected.and_then(|_| pids.last().copied());
+                        }
+                        Key::Left => horizontal_offset = horizontal_offset.saturating_sub(8),
+                        Key::Right => horizontal_offset += 8,
+                        _ => (),
+                    },
+                    Command::Wake => {
+                        update = true;
+                        message = None;
+                    }
                     _ => (),
                 }
             }
 
-            prev_term_width = view.term_info.width;
-            prev_term_height = view.term_info.height;
+            if update || rebuild {
+                let detail = detail.as_ref().map(|x| x.pid);
+                view = View::new(opt, config, true, detail)?;
+            }
         }
         Ok(())
     }